@@ -6,207 +6,51 @@ use std::hash::{Hash, Hasher};
 
 use crate::{
     classification::logic::group_by_hash,
+    methodology::configuration::CONFIG,
     modules::algorithm_experiments_modules::algorithm_experiments::AlgorithmExperiments,
     modules::classification::{
         algorithm_info::AlgorithmInfo, classification_result::ClassificationResult,
-        family_category::FamilyCategory, family_group::FamilyGroup, family_summary::FamilySummary,
+        family_category::FamilyCategory,
+        family_group::FamilyGroup,
+        family_spec::{ClassificationFamilySpec, FamilyAggregation, FamilyMetric},
+        family_summary::FamilySummary,
     },
+    modules::viewer_assets::viewer_styles,
 };
 
+/// Writes `<root_name>classification.html` and returns the families it shows
 pub fn export_classification(
     experiments: &[AlgorithmExperiments],
     output_path: &str,
     root_name: &str,
-) {
-    let mut families = Vec::new();
+) -> ClassificationResult {
+    let declared = CONFIG.classification_families.iter().filter(|spec| match spec.validate() {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("✗ Skipping classification {}", e);
+            false
+        }
+    });
 
-    // ===== GLOBAL METRICS (Families 1-3) =====
-    families.push(create_family_category(
-        experiments,
-        1,
-        "Number of Rules",
-        "Classification based on the total number of rules in the algorithm",
-        |a| a.hash_by_rules_count(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        2,
-        "Number of Rounds in a Cycle",
-        "Classification based on the number of rounds within cycles across executions",
-        |a| a.hash_by_cycle_len_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        3,
-        "Energy in a Cycle",
-        "Classification based on the total activation energy within cycles across executions",
-        |a| a.hash_by_total_activation_in_cycle_in_executions(),
-    ));
-    families.push(create_family_category(
-        experiments,
-        4,
-        "Paths in a Cycle",
-        "Classification based on the paths robots take within cycles across executions",
-        |a| a.hash_by_cycle_paths_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        5,
-        "Number of rules without movement",
-        "Classification based on the number of idle rules in the algorithm",
-        |a| a.hash_by_idle_rules_count(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        6,
-        "Number of rules with invisible zone",
-        "Classification based on the number of opacity rules in the algorithm",
-        |a| a.hash_by_opac_rules_count(),
-    ));
-
-    // ===== BY ROBOT COLOR (Families 4-6) =====
-    families.push(create_family_category(
-        experiments,
-        7,
-        "Number of rules for each color",
-        "Classification based on the number of rules per robot color",
-        |a| a.hash_family_by_rules_count_by_colors(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        8,
-        "Number of rules without movement for each color",
-        "Classification based on the number of idle rules per robot color",
-        |a| a.hash_family_by_idle_rules_count_by_colors(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        9,
-        "Number of rules with invisible zone for each color",
-        "Classification based on the number of opacity rules per robot color",
-        |a| a.hash_family_by_opacity_rules_count_by_colors(),
-    ));
-
-    // ===== EXECUTION PATTERNS - TOTAL (Families 7-11) =====
-    families.push(create_family_category(
-        experiments,
-        10,
-        "Number of rules used in an execution",
-        "Classification based on the number of rules used across executions",
-        |a| a.hash_by_rules_count_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        11,
-        "Energy in an execution",
-        "Classification based on the total energy consumed across executions",
-        |a| a.hash_by_total_activation_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        12,
-        "Number of color changes in an execution",
-        "Classification based on the energy consumed for color changes across executions",
-        |a| a.hash_by_color_activation_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        13,
-        "Number of movements in an execution",
-        "Classification based on the energy consumed for movements across executions",
-        |a| a.hash_by_movement_activation_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        14,
-        "Number of rounds in an execution",
-        "Classification based on the total number of rounds taken across executions",
-        |a| a.hash_by_steps_taken_in_executions(),
-    ));
-
-    // ===== EXECUTION PATTERNS - BY ROBOT (Families 13-16) =====
-    families.push(create_family_category(
-        experiments,
-        15,
-        "Number of rules used in an execution for each robot",
-        "Classification based on the number of rules used per robot across executions",
-        |a| a.hash_by_rules_count_in_executions_by_robot(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        16,
-        "Energy in an execution for each robot",
-        "Classification based on the total energy consumed per robot across executions",
-        |a| a.hash_by_activation_in_executions_by_robot(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        17,
-        "Number of color changes in an execution for each robot",
-        "Classification based on the energy consumed for color changes per robot across executions",
-        |a| a.hash_by_color_activation_in_executions_by_robot(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        18,
-        "Number of movements in an execution for each robot",
-        "Classification based on the energy consumed for movements per robot across executions",
-        |a| a.hash_by_movement_activation_in_executions_by_robot(),
-    ));
-
-    // ===== FINAL POSITIONS AND PATHS (Families 17-19) =====
-    families.push(create_family_category(
-        experiments,
-        19,
-        "Sequence of colors in an execution",
-        "Classification based on the color changes per robot across executions",
-        |a| a.hash_by_color_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        20,
-        "Sequence of configurations in an execution",
-        "Classification based on the paths taken by each robot across executions",
-        |a| a.hash_by_paths_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        21,
-        "Paths in an execution",
-        "Classification based on the positions occupied by each robot across executions",
-        |a| a.hash_by_positions_in_executions(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        22,
-        "Energy in the execution prefix before the cycle",
-        "Classification based on the energy consumed before entering a cycle across executions",
-        |a| a.hash_by_activation_in_executions_before_cycle(),
-    ));
-
-    families.push(create_family_category(
-        experiments,
-        23,
-        "Paths in the execution prefix before the cycle",
-        "Classification based on the paths taken before entering a cycle across executions",
-        |a| a.hash_by_paths_before_cycle_in_executions(),
-    ));
+    let mut families = Vec::new();
+    for family in built_in_families()
+        .into_iter()
+        .chain(declared.cloned().map(ClassificationFamily::Spec))
+    {
+        let family_num = families.len() + 1;
+        families.push(match family {
+            ClassificationFamily::Spec(spec) => create_family_category(
+                experiments,
+                family_num,
+                &spec.title,
+                &spec.description,
+                |a| spec.signature(a),
+            ),
+            ClassificationFamily::Sequence(title, description, signature) => {
+                create_family_category(experiments, family_num, title, description, signature)
+            }
+        });
+    }
 
     // Generate summaries
     let summaries: Vec<FamilySummary> = families
@@ -244,6 +88,164 @@ pub fn export_classification(
         Ok(_) => {}
         Err(e) => eprintln!("✗ Failed to generate HTML: {}", e),
     }
+    result
+}
+
+/// A classification family. The numeric ones are `ClassificationFamilySpec`s, like the
+/// families declared in config.json. The path and color families compare whole
+/// sequences of positions or colors per robot, not values a spec can sum or bucket,
+/// so they keep their signature function.
+enum ClassificationFamily {
+    Spec(ClassificationFamilySpec),
+    Sequence(&'static str, &'static str, fn(&AlgorithmExperiments) -> String),
+}
+
+/// The built-in families, in display order
+fn built_in_families() -> Vec<ClassificationFamily> {
+    use ClassificationFamily::*;
+    use FamilyAggregation::*;
+    use FamilyMetric::*;
+    vec![
+        // ===== GLOBAL METRICS =====
+        Spec(ClassificationFamilySpec::exact(
+            "Number of Rules",
+            "Classification based on the total number of rules in the algorithm",
+            RulesCount,
+            Sum,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of Rounds in a Cycle",
+            "Classification based on the number of rounds within cycles across executions",
+            CycleLen,
+            PerExperiment,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Energy in a Cycle",
+            "Classification based on the total activation energy within cycles across executions",
+            ActivationInCycle,
+            PerExperiment,
+        )),
+        Sequence(
+            "Paths in a Cycle",
+            "Classification based on the paths robots take within cycles across executions",
+            AlgorithmExperiments::hash_by_cycle_paths_in_executions,
+        ),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules without movement",
+            "Classification based on the number of idle rules in the algorithm",
+            IdleRulesCount,
+            Sum,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules with invisible zone",
+            "Classification based on the number of opacity rules in the algorithm",
+            OpacityRulesCount,
+            Sum,
+        )),
+        // ===== BY ROBOT COLOR =====
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules for each color",
+            "Classification based on the number of rules per robot color",
+            RulesCount,
+            PerColor,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules without movement for each color",
+            "Classification based on the number of idle rules per robot color",
+            IdleRulesCount,
+            PerColor,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules with invisible zone for each color",
+            "Classification based on the number of opacity rules per robot color",
+            OpacityRulesCount,
+            PerColor,
+        )),
+        // ===== EXECUTION PATTERNS - TOTAL =====
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules used in an execution",
+            "Classification based on the number of rules used across executions",
+            RulesUsed,
+            PerExperiment,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Energy in an execution",
+            "Classification based on the total energy consumed across executions",
+            Activation,
+            PerExperiment,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of color changes in an execution",
+            "Classification based on the energy consumed for color changes across executions",
+            ColorActivations,
+            PerExperiment,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of movements in an execution",
+            "Classification based on the energy consumed for movements across executions",
+            MovementActivations,
+            PerExperiment,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rounds in an execution",
+            "Classification based on the total number of rounds taken across executions",
+            StepsTaken,
+            PerExperiment,
+        )),
+        // ===== EXECUTION PATTERNS - BY ROBOT =====
+        Spec(ClassificationFamilySpec::exact(
+            "Number of rules used in an execution for each robot",
+            "Classification based on the number of rules used per robot across executions",
+            RulesUsed,
+            PerExperimentPerRobot,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Energy in an execution for each robot",
+            "Classification based on the total energy consumed per robot across executions",
+            Activation,
+            PerExperimentPerRobot,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of color changes in an execution for each robot",
+            "Classification based on the energy consumed for color changes per robot across executions",
+            ColorActivations,
+            PerExperimentPerRobot,
+        )),
+        Spec(ClassificationFamilySpec::exact(
+            "Number of movements in an execution for each robot",
+            "Classification based on the energy consumed for movements per robot across executions",
+            MovementActivations,
+            PerExperimentPerRobot,
+        )),
+        // ===== FINAL POSITIONS AND PATHS =====
+        Sequence(
+            "Sequence of colors in an execution",
+            "Classification based on the color changes per robot across executions",
+            AlgorithmExperiments::hash_by_color_in_executions,
+        ),
+        Sequence(
+            "Sequence of configurations in an execution",
+            "Classification based on the paths taken by each robot across executions",
+            AlgorithmExperiments::hash_by_paths_in_executions,
+        ),
+        Sequence(
+            "Paths in an execution",
+            "Classification based on the positions occupied by each robot across executions",
+            AlgorithmExperiments::hash_by_positions_in_executions,
+        ),
+        // ===== BEFORE THE CYCLE =====
+        Spec(ClassificationFamilySpec::exact(
+            "Energy in the execution prefix before the cycle",
+            "Classification based on the energy consumed before entering a cycle across executions",
+            ActivationBeforeCycle,
+            PerExperiment,
+        )),
+        Sequence(
+            "Paths in the execution prefix before the cycle",
+            "Classification based on the paths taken before entering a cycle across executions",
+            AlgorithmExperiments::hash_by_paths_before_cycle_in_executions,
+        ),
+    ]
 }
 
 fn create_family_category<F>(
@@ -418,6 +420,7 @@ pub fn generate_classification_html(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let json_data = serde_json::to_string(data)?;
 
+    let bundled_css = viewer_styles();
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -425,8 +428,7 @@ pub fn generate_classification_html(
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Algorithm Classification Dashboard</title>
-    <link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.2/dist/css/bootstrap.min.css" rel="stylesheet">
-    <link href="https://fonts.googleapis.com/css2?family=Inter:wght@300;400;500;600;700&display=swap" rel="stylesheet">
+    {bundled_css}
     <style>
         :root {{
             --primary-color: #4f46e5;
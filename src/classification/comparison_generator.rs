@@ -1,5 +1,6 @@
 use crate::modules::algorithm_experiments_modules::algorithm_experiments::AlgorithmExperiments;
 use crate::modules::classification::algorithm_data::AlgorithmData;
+use crate::modules::viewer_assets::viewer_styles;
 use serde::Serialize;
 use std::fs;
 use std::path::Path;
@@ -116,6 +117,7 @@ pub fn generate_multi_algorithm_viewer(
     fs::write(data_dir.join("common_data.js"), common_js_content)
         .expect("Failed to write common data file");
 
+    let bundled_css = viewer_styles();
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -123,7 +125,7 @@ pub fn generate_multi_algorithm_viewer(
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Multi-Algorithm Viewer</title>
-    <link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.2/dist/css/bootstrap.min.css" rel="stylesheet">
+    {bundled_css}
     <style>
         body {{
             background: #f5f5f5;
@@ -509,7 +511,6 @@ pub fn generate_multi_algorithm_viewer(
         </div>
     </div>
 
-    <script src="https://cdn.jsdelivr.net/npm/bootstrap@5.3.2/dist/js/bootstrap.bundle.min.js"></script>
     <script src="multiview_core_data/common_data.js"></script>
     <script>
         let currentExperimentIndex = -1;
@@ -7,8 +7,10 @@ use crate::classification::comparison_generator::generate_multi_algorithm_viewer
 use crate::methodology::configuration::CONFIG;
 use crate::modules::algorithm_experiments_modules::algorithm_experiments::AlgorithmExperiments;
 use crate::modules::algorithm_status::AlgorithmStatus;
+use crate::modules::classification::classification_result::ClassificationResult;
 
-pub fn classify(experiments_path: &str, output_path: &str) {
+/// Classifies the `_details` JSON files of `experiments_path`; `None` when there are none
+pub fn classify(experiments_path: &str, output_path: &str) -> Option<ClassificationResult> {
     //let experiments_path = "src/to_classify";
     let mut experiments = get_experiment_files(experiments_path);
     println!("Found {} experiment files to classify.", experiments.len());
@@ -16,7 +18,7 @@ pub fn classify(experiments_path: &str, output_path: &str) {
     //here iw ant to check that th experiments is not empty
     if experiments.is_empty() {
         eprintln!("No experiments to classify after filtering. Exiting.");
-        return;
+        return None;
     }
 
     /*  let show_family_signatures = false; // Set to false to hide long signatures
@@ -168,9 +170,10 @@ pub fn classify(experiments_path: &str, output_path: &str) {
         );
     */
     // Export classification results to JSON
-    export_classification(&experiments, output_path, "");
+    let result = export_classification(&experiments, output_path, "");
     // generate_comparison_html(&experiments, output_path, CONFIG.number_of_robots,"");
     generate_multi_algorithm_viewer(&experiments, output_path, "");
+    Some(result)
 }
 
 fn print_family<F>(
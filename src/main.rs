@@ -10,20 +10,41 @@ mod modules;
 mod validation;
 use classification::logic::classify;
 use serde_json;
+use chrono::{DateTime, Local, NaiveDateTime};
 use std::{env, fs, path::PathBuf, process::exit};
 use validation::logic::validate;
 
 use crate::{
     methodology::{
         cache::{clean_all, load_all},
-        globals::{get_execution_root_str, init_execution_root},
+        execution_index::{compare_executions, generate_execution_index},
+        execution_report::print_execution_report,
+        globals::{get_execution_root_str, init_execution_root, set_seed},
         logic::methodology,
         simulator::run_simulation,
     },
-    modules::execution_logger::{end_logger, init_logger},
+    modules::{
+        execution_logger::{end_logger, init_logger},
+        model_check::ModelCheckVerdict,
+        shard_spec::ShardSpec,
+    },
     validation::{
+        algorithm_diff::diff_algorithms,
+        algorithm_loader::is_algorithm_file,
+        catalog::run_catalog_command,
         initial_config_generator::generate_initial_configs,
-        initial_config_viewer::initial_config_viewer_html, logic::validate_single_folder,
+        initial_config_viewer::initial_config_viewer_html,
+        logic::validate_single_folder,
+        minimality::analyze_rule_minimality,
+        model_check::model_check,
+        regress::run_regression,
+        scaling::analyze_scaling,
+        shard::{merge_shards, validate_shard},
+        state_graph::export_state_graphs,
+        stepper::run_stepper,
+        svg::render_algorithm_svg,
+        tikz::export_tikz,
+        trace::trace_experiment,
     },
 };
 
@@ -31,24 +52,11 @@ fn main() {
     let args: Vec<String> = env::args().skip(1).collect();
     match args.len() {
         // No arguments - default simulation
-        0 => {
-            init_execution_root();
-            init_logger(&get_execution_root_str(), "generation");
-            methodology();
-            end_logger();
-        }
+        0 => generate(false),
 
         // Single argument commands
         1 => match args[0].as_str() {
-            "--use-cache" => {
-                if load_all() {
-                    init_execution_root();
-
-                    init_logger(&get_execution_root_str(), "generation");
-                    run_simulation();
-                    end_logger();
-                }
-            }
+            "--use-cache" => generate(true),
 
             "--clean-cache" => {
                 clean_all();
@@ -63,6 +71,7 @@ fn main() {
                     init_logger(last_folder.to_str().unwrap(), "validation");
                     validate(last_folder.to_str().unwrap());
                     end_logger();
+                    generate_execution_index(last_folder.to_str().unwrap(), false);
                 } else {
                     println!("No execution folders found in results.");
                 }
@@ -87,7 +96,14 @@ fn main() {
 
         // Two argument commands
         2 => {
-            if args[0] == "--validate-option" {
+            if args[0] == "--seed" {
+                let Ok(seed) = args[1].parse::<u64>() else {
+                    println!("Error: --seed expects a non-negative integer, got '{}'", args[1]);
+                    return;
+                };
+                set_seed(seed);
+                generate(false);
+            } else if args[0] == "--validate-option" {
                 // Must not contain / - only base folder name
                 if args[1].contains('/') {
                     println!("Error: --validate-option requires a base folder name without '/'");
@@ -102,49 +118,176 @@ fn main() {
                     init_logger(path.to_str().unwrap(), "validation");
                     validate(path.to_str().unwrap());
                     end_logger();
+                    generate_execution_index(path.to_str().unwrap(), false);
                 } else {
                     println!("Folder does not exist: {}", path.display());
                 }
             } else if args[0] == "--validate-direct" {
-                let (execution_path, target_path) = resolve_validation_paths(&args[1]);
+                let Some(target_path) = resolve_direct_validation_target(&args[1]) else {
+                    return;
+                };
 
-                if !target_path.exists() {
+                println!(
+                    "Direct validation (no hierarchy): {}",
+                    target_path.display()
+                );
+                init_logger(target_path.to_str().unwrap(), "validation");
+                validate_single_folder(target_path.to_str().unwrap());
+                end_logger();
+            } else if args[0] == "--merge" {
+                let (_, target_path) = resolve_validation_paths(&args[1]);
+                if !target_path.is_dir() {
                     println!("Error: Folder does not exist: {}", target_path.display());
                     return;
                 }
 
-                if !target_path.is_dir() {
-                    println!("Error: Path is not a directory: {}", target_path.display());
-                    return;
+                println!("Merging shard results in: {}", target_path.display());
+                init_logger(target_path.to_str().unwrap(), "merge");
+                if merge_shards(target_path.to_str().unwrap()).is_none() {
+                    end_logger();
+                    exit(1);
+                }
+                end_logger();
+            } else if args[0] == "--svg" {
+                if render_algorithm_svg(&args[1], None, false).is_none() {
+                    exit(1);
                 }
+            } else if args[0] == "--state-graph" {
+                if export_state_graphs(&args[1], None).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--tikz" {
+                if export_tikz(&args[1]).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--report" {
+                if !print_execution_report(&args[1..]) {
+                    exit(1);
+                }
+            } else if args[0] == "--minimize" {
+                if analyze_rule_minimality(&args[1], false).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--catalog" {
+                if !run_catalog_command(&args[1..]) {
+                    exit(1);
+                }
+            } else {
+                println!("Unknown command: {}", args.join(" "));
+                print_usage();
+            }
+        }
 
-                // Check if folder contains .web-algo files
-                let has_algo_files = fs::read_dir(&target_path)
-                    .ok()
-                    .map(|entries| {
-                        entries
-                            .filter_map(|e| e.ok())
-                            .any(|e| e.path().extension().map_or(false, |ext| ext == "web-algo"))
-                    })
-                    .unwrap_or(false);
-
-                if !has_algo_files {
-                    println!(
-                        "Error: No .web-algo files found in: {}",
-                        target_path.display()
-                    );
-                    println!("Please specify a folder that contains algorithm files.");
+        // Three argument commands
+        3 => {
+            if args[0] == "--seed" && args[2] == "--use-cache" {
+                let Ok(seed) = args[1].parse::<u64>() else {
+                    println!("Error: --seed expects a non-negative integer, got '{}'", args[1]);
+                    return;
+                };
+                set_seed(seed);
+                generate(true);
+            } else if args[0] == "--compare-executions" {
+                let (path_a, _) = resolve_validation_paths(&args[1]);
+                let (path_b, _) = resolve_validation_paths(&args[2]);
+                if !compare_executions(path_a.to_str().unwrap(), path_b.to_str().unwrap()) {
+                    exit(1);
+                }
+            } else if args[0] == "--report" {
+                if !print_execution_report(&args[1..]) {
+                    exit(1);
+                }
+            } else if args[0] == "--diff" {
+                // Exit status follows diff(1): 0 equivalent, 1 different or unreadable
+                if diff_algorithms(&args[1], &args[2]) != Some(true) {
+                    exit(1);
+                }
+            } else if args[0] == "--model-check" {
+                let Some((max_columns, max_rows)) = args[2]
+                    .split_once('x')
+                    .and_then(|(columns, rows)| Some((columns.parse::<i16>().ok()?, rows.parse::<i16>().ok()?)))
+                    .filter(|&(columns, rows)| columns > 0 && rows > 0)
+                else {
+                    println!("Error: --model-check expects a grid bound NxM, got '{}'", args[2]);
                     return;
+                };
+                // Exit 1 when the property is refuted
+                if model_check(&args[1], max_columns, max_rows) != Some(ModelCheckVerdict::Proved) {
+                    exit(1);
+                }
+            } else if args[0] == "--catalog" {
+                if !run_catalog_command(&args[1..]) {
+                    exit(1);
+                }
+            } else if args[0] == "--regress" {
+                if !run_regression(&args[1], &args[2], false) {
+                    exit(1);
+                }
+            } else if args[0] == "--scaling" {
+                let Ok(max_side) = args[2].parse::<i16>() else {
+                    println!("Error: --scaling expects the largest grid side, got '{}'", args[2]);
+                    return;
+                };
+                if analyze_scaling(&args[1], max_side).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--minimize" && args[2] == "--greedy" {
+                if analyze_rule_minimality(&args[1], true).is_none() {
+                    exit(1);
                 }
+            } else {
+                println!("Unknown command: {}", args.join(" "));
+                print_usage();
+            }
+        }
+
+        // Four argument commands
+        4 => {
+            if args[0] == "--validate-direct" && args[2] == "--shard" {
+                let Some(shard) = ShardSpec::parse(&args[3]) else {
+                    println!("Error: --shard expects i/n with 0 <= i < n, got '{}'", args[3]);
+                    return;
+                };
+                let Some(target_path) = resolve_direct_validation_target(&args[1]) else {
+                    return;
+                };
 
-                println!(
-                    "Direct validation (no hierarchy): {}",
-                    target_path.display()
-                );
-                println!("Using execution context: {}", execution_path.display());
                 init_logger(target_path.to_str().unwrap(), "validation");
-                validate_single_folder(target_path.to_str().unwrap());
+                validate_shard(target_path.to_str().unwrap(), shard);
                 end_logger();
+            } else if args[0] == "--trace" && args[2] == "--experiment" {
+                let Ok(experiment_id) = args[3].parse::<usize>() else {
+                    println!("Error: --experiment expects an experiment id, got '{}'", args[3]);
+                    return;
+                };
+                if trace_experiment(&args[1], experiment_id).is_none() {
+                    exit(1);
+                }
+            } else if (args[0] == "--svg" || args[0] == "--svg-frames") && args[2] == "--experiment" {
+                let Ok(experiment_id) = args[3].parse::<usize>() else {
+                    println!("Error: --experiment expects an experiment id, got '{}'", args[3]);
+                    return;
+                };
+                let frames = args[0] == "--svg-frames";
+                if render_algorithm_svg(&args[1], Some(experiment_id), frames).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--state-graph" && args[2] == "--grid" {
+                let Ok(grid_index) = args[3].parse::<usize>() else {
+                    println!("Error: --grid expects a grid id, got '{}'", args[3]);
+                    return;
+                };
+                if export_state_graphs(&args[1], Some(grid_index)).is_none() {
+                    exit(1);
+                }
+            } else if args[0] == "--regress" && args[3] == "--update" {
+                if !run_regression(&args[1], &args[2], true) {
+                    exit(1);
+                }
+            } else if args[0] == "--step" && args[2] == "--experiment" {
+                if !run_stepper(&args[1], &args[3]) {
+                    exit(1);
+                }
             } else {
                 println!("Unknown command: {}", args.join(" "));
                 print_usage();
@@ -159,6 +302,59 @@ fn main() {
     }
 }
 
+/// Generates a new execution, from the cache when `use_cache`
+fn generate(use_cache: bool) {
+    if use_cache && !load_all() {
+        return;
+    }
+    init_execution_root();
+    init_logger(&get_execution_root_str(), "generation");
+    if use_cache {
+        run_simulation();
+    } else {
+        methodology();
+    }
+    end_logger();
+    generate_execution_index(&get_execution_root_str(), true);
+}
+
+/// Resolves a `--validate-direct` target and checks that it holds algorithm files
+fn resolve_direct_validation_target(input: &str) -> Option<PathBuf> {
+    let (execution_path, target_path) = resolve_validation_paths(input);
+
+    if !target_path.exists() {
+        println!("Error: Folder does not exist: {}", target_path.display());
+        return None;
+    }
+
+    if !target_path.is_dir() {
+        println!("Error: Path is not a directory: {}", target_path.display());
+        return None;
+    }
+
+    // Check if folder contains algorithm files (.web-algo, .yaml/.yml or .json)
+    let has_algo_files = fs::read_dir(&target_path)
+        .ok()
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| is_algorithm_file(&e.path()))
+        })
+        .unwrap_or(false);
+
+    if !has_algo_files {
+        println!(
+            "Error: No algorithm files (.web-algo, .yaml, .json) found in: {}",
+            target_path.display()
+        );
+        println!("Please specify a folder that contains algorithm files.");
+        return None;
+    }
+
+    println!("Using execution context: {}", execution_path.display());
+    Some(target_path)
+}
+
 fn resolve_validation_paths(input: &str) -> (PathBuf, PathBuf) {
     // Extract execution folder name from various path formats
     let execution_folder = extract_execution_folder(input);
@@ -222,12 +418,31 @@ fn print_usage() {
     println!("Usage:");
     println!("  cargo run                           # Default behavior");
     println!("  cargo run -- --use-cache            # Use cached data");
+    println!("  cargo run -- --seed N [--use-cache] # Deterministic run in results/Execution_seed-N");
+    println!("  cargo run -- --compare-executions NAME_A NAME_B # Check two runs generated byte-identical algorithms");
     println!("  cargo run -- --clean-cache          # Clean all cache files");
     println!("  cargo run -- --classify             # Run classification");
     println!("  cargo run -- --validate             # Run validation");
     println!("  cargo run -- --validate-last        # Validate last execution");
     println!("  cargo run -- --validate-option NAME # Validate with hierarchy check");
     println!("  cargo run -- --validate-direct PATH # Direct validation (skip hierarchy)");
+    println!("  cargo run -- --validate-direct PATH --shard i/n # Validate shard i of n (0-based)");
+    println!("  cargo run -- --merge PATH           # Merge shard results of PATH");
+    println!("  cargo run -- --trace ALGO --experiment ID # Trace one experiment round by round");
+    println!("  cargo run -- --step ALGO --experiment ID|GRID.json # Step through a run in the terminal");
+    println!("  cargo run -- --svg ALGO                   # Rule sheet as SVG");
+    println!("  cargo run -- --svg ALGO --experiment ID   # Rule sheet and animated SVG of the run");
+    println!("  cargo run -- --svg-frames ALGO --experiment ID # Rule sheet and numbered SVG frames");
+    println!("  cargo run -- --report PATH                # Where time went, from the *.events.jsonl logs");
+    println!("  cargo run -- --report PATH_A PATH_B       # Compare stage times and counts of two executions");
+    println!("  cargo run -- --state-graph ALGO [--grid ID] # State graph (DOT + JSON) per grid");
+    println!("  cargo run -- --tikz PATH                  # LaTeX/TikZ rule figures for an algorithm or folder");
+    println!("  cargo run -- --minimize ALGO [--greedy]   # Never-fired and droppable rules, minimal .web-algo subsets");
+    println!("  cargo run -- --diff ALGO_A ALGO_B         # Equivalence up to rotation and color permutation, else rule diff");
+    println!("  cargo run -- --model-check ALGO NxM       # Check every reachable cycle covers every grid up to NxM");
+    println!("  cargo run -- --scaling ALGO MAX_SIDE      # Rounds to cover and cycle length against rows x columns, CSV + fits");
+    println!("  cargo run -- --catalog list|show HASH|search TERM|ALGO # Validated algorithms of every execution (results/catalog)");
+    println!("  cargo run -- --regress PATH MANIFEST [--update] # Compare statuses, steps and cycle lengths to a baseline");
     println!("  cargo run -- --classify             # Run classification");
     println!("");
     println!("Examples for --validate-option (with hierarchy):");
@@ -237,6 +452,17 @@ fn print_usage() {
     println!("Examples for --validate-direct (no hierarchy, just validate folder):");
     println!("  cargo run --release -- --validate-direct Execution_2025-10-14_16-05-22/Algos/75_contains_1");
     println!("  cargo run --release -- --validate-direct Execution_2025-10-14_16-05-22/Algos/90_contains_2");
+    println!("");
+    println!("Examples for sharded validation (run each shard anywhere, then merge):");
+    println!("  cargo run --release -- --validate-direct Execution_2025-10-14_16-05-22/Algos/75_contains_1 --shard 0/2");
+    println!("  cargo run --release -- --validate-direct Execution_2025-10-14_16-05-22/Algos/75_contains_1 --shard 1/2");
+    println!("  cargo run --release -- --merge Execution_2025-10-14_16-05-22/Algos/75_contains_1");
+    println!("");
+    println!("Example for tracing (writes _traces/ next to the algorithm):");
+    println!("  cargo run --release -- --trace Execution_2025-10-14_16-05-22/Algos/75_contains_1/algo_1_act_75.web-algo --experiment 12");
+    println!("");
+    println!("Example for the terminal stepper (a trace JSON can be used as grid spec):");
+    println!("  cargo run --release -- --step Execution_2025-10-14_16-05-22/Algos/75_contains_1/algo_1_act_75.web-algo --experiment 12");
 }
 
 fn get_last_execution_folder() -> Option<PathBuf> {
@@ -249,11 +475,28 @@ fn get_last_execution_folder() -> Option<PathBuf> {
         .ok()?
         .filter_map(|e| e.ok())
         .map(|e| e.path())
-        .filter(|p| p.is_dir())
+        .filter(|p| {
+            p.is_dir()
+                && p.file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with("Execution_"))
+        })
         .collect();
 
-    // Sort existing folder names (Execution_YYYY-MM-DD_HH-MM-SS) lexicographically
-    entries.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    // Execution_YYYY-MM-DD_HH-MM-SS folders are dated by their name; seeded folders
+    // (Execution_seed-N) by their creation time
+    entries.sort_by_key(|p| {
+        let name = p.file_name().map(|name| name.to_string_lossy().into_owned());
+        let date = name
+            .as_deref()
+            .and_then(|name| NaiveDateTime::parse_from_str(name, "Execution_%Y-%m-%d_%H-%M-%S").ok())
+            .or_else(|| {
+                let created = fs::metadata(p)
+                    .and_then(|m| m.created().or_else(|_| m.modified()))
+                    .ok()?;
+                Some(DateTime::<Local>::from(created).naive_local())
+            });
+        (date, name)
+    });
 
     entries.pop() // return the last existing one
 }
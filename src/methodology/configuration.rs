@@ -1,15 +1,31 @@
-use std::{fs, path::Path, process::exit};
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    path::Path,
+    process::exit,
+};
 
+use fxhash::FxHasher;
 use once_cell::sync::Lazy;
 
 use crate::modules::{
     combination_mode::CombinationMode, config::Config, generation_mode::GenerationMode,
     grid_config, init_config, simulation_config::SimulationConfig, validation_config,
+    validation_policy::ValidationPolicy,
 };
 //pub static MAX_EXPLORATION_STEPS: usize = 2000;
 pub static CONFIG: Lazy<Config> = Lazy::new(|| load_or_create_config());
 pub static COMBINATION_MODE: CombinationMode = CombinationMode::Sequential;
 
+/// Hash of the whole loaded config, to tell executions of different configs apart
+pub fn config_fingerprint() -> String {
+    let mut hasher = FxHasher::default();
+    serde_json::to_string(&*CONFIG)
+        .expect("Failed to serialize config")
+        .hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
 fn load_or_create_config() -> Config {
     let path = "config.json";
 
@@ -47,6 +63,8 @@ pub fn create_config_algo1() -> Config {
         generation_mode: GenerationMode::All,
         opacity: true,
         is_obstacle_opaque: true,
+        validation_policy: ValidationPolicy::RunAll,
+        streaming_combination: None,
         web_algo_colors: r#"  L: 16711680
   F: 255
   R: 32768
@@ -84,6 +102,7 @@ WWWWWWWWW"#
             (vec![('O', 0, 0), ('L', 1, -1), ('F', 0, -1)], false),
             (vec![('O', 0, 0), ('L', 0, -1), ('F', -1, -1)], true),
         ],
+        classification_families: vec![],
     }
 }
 
@@ -199,6 +218,8 @@ pub fn create_config_algo2() -> Config {
         generation_mode: GenerationMode::ProgressiveValidationByLevels(4),
         opacity: true,
         is_obstacle_opaque: true,
+        validation_policy: ValidationPolicy::RunAll,
+        streaming_combination: None,
         web_algo_colors: "  L: 16711680\n  F: 255\n  O: 16753920".to_string(),
 
         web_algo_initial_configuration: ".\nWWWWWWWWWWWWWWW\nW.............W\nW.............W\nW.............W\nW.............W\nW...F.L.......W\nW.............W\nW......O......W\nW.............W\nW.............W\nW.............W\nW.............W\nW.............W\nW.............W\nWWWWWWWWWWWWWWW".to_string(),
@@ -242,6 +263,7 @@ pub fn create_config_algo2() -> Config {
             (vec![('O', 0, 0), ('L', 2, -2), ('F', 0, -2)], false),
             (vec![('O', 0, 0), ('L', 0, -2), ('F', -2, -2)], true),
         ],
+        classification_families: vec![],
     }
 }
 
@@ -0,0 +1,592 @@
+// ============================================================================
+// EXECUTION INDEX
+// ============================================================================
+//
+// Writes `index.html` and `manifest.json` at the root of an execution folder.
+// Both are rebuilt from what is on disk (logs, `Algos/` folders, viewers), so
+// the index can be regenerated after a later validation of the same folder.
+// The manifest also records the provenance of the run (crate version, git
+// revision, config hash, seed) and the content hash of its algorithm set;
+// `compare_executions` checks two runs produced byte-identical algorithms.
+//
+// ============================================================================
+
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::process::Command;
+
+use chrono::Local;
+use fxhash::FxHasher;
+
+use crate::{
+    methodology::{
+        configuration::{config_fingerprint, CONFIG},
+        globals::get_seed,
+    },
+    modules::{
+        execution_manifest::{
+            AlgorithmFileHash, AlgorithmSet, Artifact, ArtifactKind, ExecutionManifest,
+            LevelSummary, LogTimings, Provenance, TimingNote,
+        },
+        viewer_assets::viewer_styles,
+    },
+};
+
+const INDEX_FILE: &str = "index.html";
+const MANIFEST_FILE: &str = "manifest.json";
+const ALGOS_FOLDER: &str = "Algos";
+/// Folders whose content is only read by the viewers next to them
+const HIDDEN_FOLDERS: [&str; 3] = ["_details", "multiview_core_data", "_shards"];
+
+/// Writes `index.html` and `manifest.json` for the execution folder `execution_root`.
+/// `new_execution` records the current config and provenance; otherwise those of the
+/// previous manifest are kept (none for folders that never had one).
+pub fn generate_execution_index(execution_root: &str, new_execution: bool) {
+    let root = Path::new(execution_root);
+    let manifest = build_manifest(root, new_execution);
+
+    let manifest_json =
+        serde_json::to_string_pretty(&manifest).expect("Failed to serialize execution manifest");
+    if let Err(e) = fs::write(root.join(MANIFEST_FILE), manifest_json) {
+        eprintln!("Failed to write {}: {}", MANIFEST_FILE, e);
+        return;
+    }
+    if let Err(e) = fs::write(root.join(INDEX_FILE), generate_index_html(&manifest)) {
+        eprintln!("Failed to write {}: {}", INDEX_FILE, e);
+        return;
+    }
+
+    println!("✅ Generated execution index: {}/{}", execution_root, INDEX_FILE);
+}
+
+fn build_manifest(root: &Path, new_execution: bool) -> ExecutionManifest {
+    let execution_name = root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| root.display().to_string());
+
+    // Keep the config and provenance of the run that created the folder when the
+    // index is rebuilt later: the current config.json may not be the one it used
+    let (config, provenance) = if new_execution {
+        (Some(CONFIG.clone()), Some(current_provenance()))
+    } else {
+        read_previous_manifest(root)
+            .map(|manifest| (manifest.config, manifest.provenance))
+            .unwrap_or((None, None))
+    };
+
+    let mut logs: Vec<LogTimings> = list_files(root)
+        .into_iter()
+        .filter(|name| name.ends_with(".log"))
+        .filter_map(|name| parse_log_timings(&root.join(&name), name))
+        .collect();
+    logs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let levels = collect_levels(root);
+    let level_folders: Vec<String> = levels.iter().map(|level| level.folder.clone()).collect();
+
+    let mut artifacts = Vec::new();
+    collect_artifacts(root, root, &level_folders, &mut artifacts);
+    artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    ExecutionManifest {
+        execution_name,
+        generated_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        config,
+        provenance,
+        algorithm_set: Some(algorithm_set(root)),
+        logs,
+        levels,
+        artifacts,
+    }
+}
+
+fn read_previous_manifest(root: &Path) -> Option<ExecutionManifest> {
+    let content = fs::read_to_string(root.join(MANIFEST_FILE)).ok()?;
+    serde_json::from_str::<ExecutionManifest>(&content).ok()
+}
+
+fn current_provenance() -> Provenance {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    Provenance {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        git_revision: git(&["rev-parse", "HEAD"]),
+        git_dirty: git(&["status", "--porcelain", "--untracked-files=no"])
+            .is_some_and(|status| !status.is_empty()),
+        config_hash: config_fingerprint(),
+        seed: get_seed(),
+    }
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = FxHasher::default();
+    bytes.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Hashes every `.web-algo` file under `Algos/`
+pub fn algorithm_set(root: &Path) -> AlgorithmSet {
+    fn collect(root: &Path, dir: &Path, files: &mut Vec<AlgorithmFileHash>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect(root, &path, files);
+            } else if path.extension().is_some_and(|ext| ext == "web-algo") {
+                if let Ok(bytes) = fs::read(&path) {
+                    files.push(AlgorithmFileHash {
+                        path: relative_path(root, &path),
+                        hash: content_hash(&bytes),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    collect(root, &root.join(ALGOS_FOLDER), &mut files);
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut hasher = FxHasher::default();
+    files.hash(&mut hasher);
+    AlgorithmSet {
+        hash: format!("{:016x}", hasher.finish()),
+        files,
+    }
+}
+
+/// Compares the algorithm sets of two execution folders file by file; true when
+/// they are byte-identical
+pub fn compare_executions(execution_a: &str, execution_b: &str) -> bool {
+    let roots = [Path::new(execution_a), Path::new(execution_b)];
+    for root in roots {
+        if !root.join(ALGOS_FOLDER).is_dir() {
+            eprintln!("❌ No {} folder in {}", ALGOS_FOLDER, root.display());
+            return false;
+        }
+    }
+
+    for root in roots {
+        let provenance = read_previous_manifest(root).and_then(|manifest| manifest.provenance);
+        match provenance {
+            Some(provenance) => println!(
+                "{}: v{}, revision {}{}, config {}, seed {}",
+                root.display(),
+                provenance.crate_version,
+                provenance.git_revision.as_deref().unwrap_or("unknown"),
+                if provenance.git_dirty { " (modified)" } else { "" },
+                provenance.config_hash,
+                provenance
+                    .seed
+                    .map_or("none".to_string(), |seed| seed.to_string())
+            ),
+            None => println!("{}: no provenance recorded", root.display()),
+        }
+    }
+
+    let [set_a, set_b] = roots.map(algorithm_set);
+    if set_a.hash == set_b.hash && set_a.files == set_b.files {
+        println!(
+            "✅ Identical algorithm sets: {} files, hash {}",
+            set_a.files.len(),
+            set_a.hash
+        );
+        return true;
+    }
+
+    println!(
+        "❌ Algorithm sets differ: {} files ({}) vs {} files ({})",
+        set_a.files.len(),
+        set_a.hash,
+        set_b.files.len(),
+        set_b.hash
+    );
+    for file in &set_a.files {
+        match set_b.files.iter().find(|other| other.path == file.path) {
+            None => println!("  only in {}: {}", execution_a, file.path),
+            Some(other) if other.hash != file.hash => println!("  differs: {}", file.path),
+            Some(_) => {}
+        }
+    }
+    for file in &set_b.files {
+        if !set_a.files.iter().any(|other| other.path == file.path) {
+            println!("  only in {}: {}", execution_b, file.path);
+        }
+    }
+    false
+}
+
+/// Names of the regular files directly inside `dir`, sorted
+fn list_files(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_file())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn artifact_kind(path: &Path) -> Option<ArtifactKind> {
+    if path.is_dir() {
+        return Some(ArtifactKind::Folder);
+    }
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("html") => Some(ArtifactKind::Viewer),
+        Some("log") => Some(ArtifactKind::Log),
+        Some("txt") | Some("json") | Some("jsonl") | Some("csv") => Some(ArtifactKind::Data),
+        _ => None,
+    }
+}
+
+/// Parses the header, timed notes and footer written by `ExecutionLogger`
+fn parse_log_timings(path: &Path, name: String) -> Option<LogTimings> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut timings = LogTimings {
+        path: name,
+        started: None,
+        duration: None,
+        notes: Vec::new(),
+    };
+
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("⏱ [") {
+            if let Some((elapsed, note)) = rest.split_once("]  •  ") {
+                timings.notes.push(TimingNote {
+                    elapsed: elapsed.to_string(),
+                    note: note.to_string(),
+                });
+            }
+        } else if let Some(rest) = line.strip_prefix("│ Time      :") {
+            timings.started = Some(rest.trim_end_matches('│').trim().to_string());
+        } else if let Some(rest) = line.strip_prefix("│ Duration :") {
+            timings.duration = Some(rest.trim_end_matches('│').trim().to_string());
+        }
+    }
+
+    // Only logs written by ExecutionLogger
+    if timings.started.is_none() && timings.notes.is_empty() {
+        return None;
+    }
+    Some(timings)
+}
+
+/// Validated algorithm folders: `Algos/` itself (All mode) or its level subfolders
+fn collect_levels(root: &Path) -> Vec<LevelSummary> {
+    let algos_path = root.join(ALGOS_FOLDER);
+    let mut candidates = vec![algos_path.clone()];
+    if let Ok(entries) = fs::read_dir(&algos_path) {
+        candidates.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir()),
+        );
+    }
+
+    let mut levels: Vec<LevelSummary> = candidates
+        .iter()
+        .filter_map(|folder| summarize_level(root, folder))
+        .collect();
+    levels.sort_by(|a, b| {
+        (a.activation_level, &a.folder).cmp(&(b.activation_level, &b.folder))
+    });
+    levels
+}
+
+fn summarize_level(root: &Path, folder: &Path) -> Option<LevelSummary> {
+    let files = list_files(folder);
+    let algorithm_count = files
+        .iter()
+        .filter(|name| name.ends_with(".web-algo"))
+        .count();
+    if algorithm_count == 0 {
+        return None;
+    }
+
+    let folder_name = folder.file_name()?.to_string_lossy().into_owned();
+    let activation_level = folder_name
+        .split_once("_contains_")
+        .and_then(|(level, _)| level.parse::<usize>().ok());
+
+    let status_counts = fs::read_to_string(folder.join("_validation_results.log"))
+        .map(|content| parse_status_counts(&content))
+        .unwrap_or_default();
+
+    let mut artifacts: Vec<Artifact> = files
+        .iter()
+        .map(|name| folder.join(name))
+        .filter_map(|path| {
+            artifact_kind(&path).map(|kind| Artifact {
+                kind,
+                path: relative_path(root, &path),
+            })
+        })
+        .collect();
+    let details = folder.join("_details");
+    if details.is_dir() {
+        artifacts.push(Artifact {
+            kind: ArtifactKind::Folder,
+            path: relative_path(root, &details),
+        });
+    }
+
+    Some(LevelSummary {
+        folder: relative_path(root, folder),
+        activation_level,
+        algorithm_count,
+        status_counts,
+        artifacts,
+    })
+}
+
+/// Reads the `— Label: count/total` lines of a `_validation_results.log` summary
+fn parse_status_counts(content: &str) -> Vec<(String, usize)> {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix("— "))
+        .filter_map(|line| {
+            let (label, value) = line.split_once(':')?;
+            let count = value.trim().split('/').next()?.parse::<usize>().ok()?;
+            Some((label.trim().to_string(), count))
+        })
+        .collect()
+}
+
+/// Every viewer, log and data file outside the level folders
+fn collect_artifacts(root: &Path, dir: &Path, level_folders: &[String], out: &mut Vec<Artifact>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let relative = relative_path(root, &path);
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        if path.is_dir() {
+            if !HIDDEN_FOLDERS.contains(&name.as_str()) && !level_folders.contains(&relative) {
+                collect_artifacts(root, &path, level_folders, out);
+            }
+            continue;
+        }
+        if dir == root && (name == INDEX_FILE || name == MANIFEST_FILE) {
+            continue;
+        }
+        if let Some(kind) = artifact_kind(&path) {
+            out.push(Artifact {
+                kind,
+                path: relative,
+            });
+        }
+    }
+}
+
+/*******************************************************
+ *                                                     *
+ *                      HTML                           *
+ *                                                     *
+ *******************************************************/
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn artifact_link(artifact: &Artifact) -> String {
+    let label = match artifact.kind {
+        ArtifactKind::Folder => format!("{}/", artifact.path),
+        _ => artifact.path.clone(),
+    };
+    format!(
+        r#"<a href="{}">{}</a>"#,
+        escape_html(&artifact.path),
+        escape_html(&label)
+    )
+}
+
+fn artifacts_section(title: &str, artifacts: &[Artifact], kind: ArtifactKind) -> String {
+    let links: Vec<String> = artifacts
+        .iter()
+        .filter(|artifact| artifact.kind == kind)
+        .map(|artifact| format!("<li>{}</li>", artifact_link(artifact)))
+        .collect();
+    if links.is_empty() {
+        return String::new();
+    }
+    format!("<h5>{}</h5>\n<ul>\n{}\n</ul>\n", title, links.join("\n"))
+}
+
+fn generate_index_html(manifest: &ExecutionManifest) -> String {
+    let bundled_css = viewer_styles();
+
+    let mut levels_rows = String::new();
+    for level in &manifest.levels {
+        let counts = level
+            .status_counts
+            .iter()
+            .map(|(label, count)| format!("{}: {}", escape_html(label), count))
+            .collect::<Vec<_>>()
+            .join("<br>");
+        let links = level
+            .artifacts
+            .iter()
+            .map(artifact_link)
+            .collect::<Vec<_>>()
+            .join("<br>");
+        levels_rows.push_str(&format!(
+            "<tr><td><a href=\"{folder}/\">{folder}</a></td><td>{level}</td><td>{count}</td><td>{counts}</td><td class=\"small\">{links}</td></tr>\n",
+            folder = escape_html(&level.folder),
+            level = level
+                .activation_level
+                .map_or("-".to_string(), |level| level.to_string()),
+            count = level.algorithm_count,
+        ));
+    }
+
+    let mut timings = String::new();
+    for log in &manifest.logs {
+        timings.push_str(&format!(
+            "<h5><a href=\"{path}\">{path}</a></h5>\n<p class=\"text-muted small\">Started: {started} &middot; Duration: {duration}</p>\n",
+            path = escape_html(&log.path),
+            started = escape_html(log.started.as_deref().unwrap_or("-")),
+            duration = escape_html(log.duration.as_deref().unwrap_or("not finished")),
+        ));
+        if !log.notes.is_empty() {
+            timings.push_str("<table class=\"table table-hover\">\n<thead><tr><th>Elapsed</th><th>Note</th></tr></thead>\n<tbody>\n");
+            for note in &log.notes {
+                timings.push_str(&format!(
+                    "<tr><td class=\"text-muted\">{}</td><td>{}</td></tr>\n",
+                    escape_html(&note.elapsed),
+                    escape_html(&note.note)
+                ));
+            }
+            timings.push_str("</tbody>\n</table>\n");
+        }
+    }
+
+    let config_json = manifest
+        .config
+        .as_ref()
+        .and_then(|config| serde_json::to_string_pretty(config).ok())
+        .unwrap_or_else(|| "No config recorded".to_string());
+
+    let provenance = manifest
+        .provenance
+        .as_ref()
+        .map(|provenance| {
+            format!(
+                "Revision {}{} &middot; config {} &middot; v{}{}",
+                escape_html(provenance.git_revision.as_deref().unwrap_or("unknown")),
+                if provenance.git_dirty { " (modified)" } else { "" },
+                escape_html(&provenance.config_hash),
+                escape_html(&provenance.crate_version),
+                provenance
+                    .seed
+                    .map_or(String::new(), |seed| format!(" &middot; seed {}", seed))
+            )
+        })
+        .unwrap_or_else(|| "No provenance recorded".to_string());
+    let algorithm_set = manifest
+        .algorithm_set
+        .as_ref()
+        .map(|set| format!(" &middot; algorithm set {} ({} files)", set.hash, set.files.len()))
+        .unwrap_or_default();
+
+    let artifacts = [
+        artifacts_section("Viewers", &manifest.artifacts, ArtifactKind::Viewer),
+        artifacts_section("Logs", &manifest.artifacts, ArtifactKind::Log),
+        artifacts_section("Data", &manifest.artifacts, ArtifactKind::Data),
+    ]
+    .concat();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{name} - Execution Index</title>
+    {bundled_css}
+    <style>
+        body {{
+            background: #f5f5f5;
+        }}
+        section {{
+            background: #fff;
+            border: 1px solid #dee2e6;
+            border-radius: 8px;
+            padding: 20px;
+            margin-bottom: 20px;
+        }}
+        pre {{
+            max-height: 400px;
+            overflow: auto;
+            background: #f8f9fa;
+            padding: 12px;
+            border-radius: 6px;
+            font-size: 0.85rem;
+        }}
+    </style>
+</head>
+<body>
+    <div class="container mt-4">
+        <h2>{name}</h2>
+        <p class="text-muted">Index generated {generated_at} &middot; <a href="{manifest_file}">{manifest_file}</a></p>
+        <p class="text-muted small">{provenance}{algorithm_set}</p>
+
+        <section>
+            <h4>Levels</h4>
+            <div class="table-responsive">
+                <table class="table table-hover">
+                    <thead><tr><th>Folder</th><th>Activation</th><th>Algorithms</th><th>Validation</th><th>Files</th></tr></thead>
+                    <tbody>
+{levels_rows}                    </tbody>
+                </table>
+            </div>
+        </section>
+
+        <section>
+            <h4>Artifacts</h4>
+{artifacts}        </section>
+
+        <section>
+            <h4>Timings</h4>
+{timings}        </section>
+
+        <section>
+            <h4>Config</h4>
+            <pre>{config}</pre>
+        </section>
+    </div>
+</body>
+</html>
+"#,
+        name = escape_html(&manifest.execution_name),
+        generated_at = escape_html(&manifest.generated_at),
+        manifest_file = MANIFEST_FILE,
+        config = escape_html(&config_json),
+    )
+}
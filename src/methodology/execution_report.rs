@@ -0,0 +1,337 @@
+// ============================================================================
+// EXECUTION REPORT
+// ============================================================================
+//
+// Reads the `*.events.jsonl` files written by `ExecutionLogger` under one or
+// two folders. With one folder, prints every run and where time went per
+// stage; with two, compares stage times and counts side by side.
+//
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::modules::{
+    execution_event::{EventKind, ExecutionEvent},
+    time_helper::format_duration_secs,
+};
+
+const EVENTS_SUFFIX: &str = ".events.jsonl";
+
+/// One logger session read back from its events file
+struct RunEvents {
+    path: PathBuf,
+    command: String,
+    started: String,
+    duration_secs: Option<f64>,
+    peak_memory_bytes: Option<u64>,
+    stages: Vec<StageEvent>,
+}
+
+struct StageEvent {
+    stage: String,
+    duration_secs: f64,
+    counts: BTreeMap<String, usize>,
+}
+
+/// Per-stage totals over all runs of a folder
+#[derive(Default)]
+struct StageTotals {
+    occurrences: usize,
+    total_secs: f64,
+    max_secs: f64,
+    counts: BTreeMap<String, usize>,
+}
+
+/// Prints the report for `paths` (one folder, or two to compare). Returns false on bad input.
+pub fn print_execution_report(paths: &[String]) -> bool {
+    let mut folders = Vec::new();
+    for path in paths {
+        let Some(folder) = resolve_folder(path) else {
+            eprintln!("❌ Folder does not exist: {}", path);
+            return false;
+        };
+        let runs = load_runs(&folder);
+        if runs.is_empty() {
+            eprintln!("❌ No *{} files under {}", EVENTS_SUFFIX, folder.display());
+            return false;
+        }
+        folders.push((folder, runs));
+    }
+
+    match folders.as_slice() {
+        [(folder, runs)] => print_summary(folder, runs),
+        [(folder_a, runs_a), (folder_b, runs_b)] => {
+            print_comparison(folder_a, runs_a, folder_b, runs_b)
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Accepts a path or an execution folder name under `results/`
+fn resolve_folder(path: &str) -> Option<PathBuf> {
+    let direct = PathBuf::from(path);
+    if direct.is_dir() {
+        return Some(direct);
+    }
+    let in_results = Path::new("results").join(path);
+    in_results.is_dir().then_some(in_results)
+}
+
+fn load_runs(folder: &Path) -> Vec<RunEvents> {
+    let mut files = Vec::new();
+    find_event_files(folder, &mut files);
+    files.sort();
+    files.iter().filter_map(|path| read_run(path)).collect()
+}
+
+fn find_event_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_event_files(&path, out);
+        } else if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(EVENTS_SUFFIX))
+        {
+            out.push(path);
+        }
+    }
+}
+
+fn read_run(path: &Path) -> Option<RunEvents> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut run = RunEvents {
+        path: path.to_path_buf(),
+        command: String::from("?"),
+        started: String::new(),
+        duration_secs: None,
+        peak_memory_bytes: None,
+        stages: Vec::new(),
+    };
+    let mut skipped_lines = 0;
+
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(event) = serde_json::from_str::<ExecutionEvent>(line) else {
+            skipped_lines += 1;
+            continue;
+        };
+        match event.kind {
+            EventKind::ExecutionStarted { command, .. } => {
+                run.command = command;
+                run.started = event.timestamp;
+            }
+            EventKind::StageFinished {
+                stage,
+                duration_secs,
+                counts,
+                memory_bytes,
+            } => {
+                run.peak_memory_bytes = run.peak_memory_bytes.max(memory_bytes);
+                run.stages.push(StageEvent {
+                    stage,
+                    duration_secs,
+                    counts,
+                });
+            }
+            EventKind::ExecutionFinished {
+                duration_secs,
+                memory_bytes,
+            } => {
+                run.duration_secs = Some(duration_secs);
+                run.peak_memory_bytes = run.peak_memory_bytes.max(memory_bytes);
+            }
+            EventKind::StageStarted { .. } | EventKind::Count { .. } | EventKind::Note { .. } => {}
+        }
+    }
+
+    if skipped_lines > 0 {
+        eprintln!(
+            "[WARN] {} unreadable lines skipped in {}",
+            skipped_lines,
+            path.display()
+        );
+    }
+    Some(run)
+}
+
+/// Stage totals in first-seen order, so the table follows the pipeline
+fn stage_totals(runs: &[RunEvents]) -> Vec<(String, StageTotals)> {
+    let mut totals: Vec<(String, StageTotals)> = Vec::new();
+    for stage in runs.iter().flat_map(|run| run.stages.iter()) {
+        let index = match totals.iter().position(|(name, _)| *name == stage.stage) {
+            Some(index) => index,
+            None => {
+                totals.push((stage.stage.clone(), StageTotals::default()));
+                totals.len() - 1
+            }
+        };
+        let entry = &mut totals[index].1;
+        entry.occurrences += 1;
+        entry.total_secs += stage.duration_secs;
+        entry.max_secs = entry.max_secs.max(stage.duration_secs);
+        for (name, value) in &stage.counts {
+            *entry.counts.entry(name.clone()).or_default() += value;
+        }
+    }
+    totals
+}
+
+fn total_run_secs(runs: &[RunEvents]) -> f64 {
+    runs.iter().filter_map(|run| run.duration_secs).sum()
+}
+
+fn format_memory(bytes: Option<u64>) -> String {
+    match bytes {
+        Some(bytes) => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+        None => "-".to_string(),
+    }
+}
+
+fn format_secs(seconds: f64) -> String {
+    format_duration_secs(seconds as f32)
+}
+
+fn print_summary(folder: &Path, runs: &[RunEvents]) {
+    println!("\n═══ Execution report: {} ═══", folder.display());
+    println!("\nRuns ({}):", runs.len());
+    for run in runs {
+        println!(
+            "  {:<14} {:<27} {:>22}  peak {:>10}  {}",
+            run.command,
+            run.started,
+            run.duration_secs
+                .map(format_secs)
+                .unwrap_or_else(|| "(unfinished)".to_string()),
+            format_memory(run.peak_memory_bytes),
+            run.path.strip_prefix(folder).unwrap_or(&run.path).display()
+        );
+    }
+
+    let total = total_run_secs(runs);
+    println!("\nStages (share of {} total run time):", format_secs(total));
+    println!(
+        "  {:<26} {:>5} {:>22} {:>22} {:>7}  counts",
+        "stage", "runs", "total", "max", "share"
+    );
+    for (stage, totals) in stage_totals(runs) {
+        let share = if total > 0.0 {
+            100.0 * totals.total_secs / total
+        } else {
+            0.0
+        };
+        let counts: Vec<String> = totals
+            .counts
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        println!(
+            "  {:<26} {:>5} {:>22} {:>22} {:>6.1}%  {}",
+            stage,
+            totals.occurrences,
+            format_secs(totals.total_secs),
+            format_secs(totals.max_secs),
+            share,
+            counts.join(" ")
+        );
+    }
+    println!();
+}
+
+fn print_comparison(folder_a: &Path, runs_a: &[RunEvents], folder_b: &Path, runs_b: &[RunEvents]) {
+    println!("\n═══ Execution comparison ═══");
+    println!("  A: {} ({} runs)", folder_a.display(), runs_a.len());
+    println!("  B: {} ({} runs)", folder_b.display(), runs_b.len());
+
+    let totals_a = stage_totals(runs_a);
+    let totals_b = stage_totals(runs_b);
+    let mut stages: Vec<&String> = totals_a.iter().map(|(stage, _)| stage).collect();
+    for (stage, _) in &totals_b {
+        if !stages.contains(&stage) {
+            stages.push(stage);
+        }
+    }
+    let find = |totals: &[(String, StageTotals)], stage: &str| {
+        totals
+            .iter()
+            .find(|(name, _)| name == stage)
+            .map(|(_, totals)| totals.total_secs)
+    };
+
+    println!(
+        "\n  {:<26} {:>22} {:>22} {:>12} {:>8}",
+        "stage", "A", "B", "B - A (s)", "B / A"
+    );
+    let mut rows: Vec<(String, Option<f64>, Option<f64>)> = stages
+        .iter()
+        .map(|stage| (stage.to_string(), find(&totals_a, stage), find(&totals_b, stage)))
+        .collect();
+    rows.push((
+        "TOTAL (runs)".to_string(),
+        Some(total_run_secs(runs_a)),
+        Some(total_run_secs(runs_b)),
+    ));
+    for (stage, a, b) in rows {
+        let delta = match (a, b) {
+            (Some(a), Some(b)) => format!("{:+.3}", b - a),
+            _ => "-".to_string(),
+        };
+        let ratio = match (a, b) {
+            (Some(a), Some(b)) if a > 0.0 => format!("{:.2}x", b / a),
+            _ => "-".to_string(),
+        };
+        println!(
+            "  {:<26} {:>22} {:>22} {:>12} {:>8}",
+            stage,
+            a.map(format_secs).unwrap_or_else(|| "-".to_string()),
+            b.map(format_secs).unwrap_or_else(|| "-".to_string()),
+            delta,
+            ratio
+        );
+    }
+
+    println!("\n  Counts that differ:");
+    let mut any_difference = false;
+    for stage in &stages {
+        let counts = |totals: &[(String, StageTotals)]| {
+            totals
+                .iter()
+                .find(|(name, _)| name == *stage)
+                .map(|(_, totals)| totals.counts.clone())
+                .unwrap_or_default()
+        };
+        let (counts_a, counts_b) = (counts(&totals_a), counts(&totals_b));
+        let mut names: Vec<&String> = counts_a.keys().chain(counts_b.keys()).collect();
+        names.sort();
+        names.dedup();
+        for name in names {
+            let (a, b) = (counts_a.get(name), counts_b.get(name));
+            if a != b {
+                any_difference = true;
+                println!(
+                    "    {}.{}: {} → {}",
+                    stage,
+                    name,
+                    a.map(|value| value.to_string()).unwrap_or_else(|| "-".to_string()),
+                    b.map(|value| value.to_string()).unwrap_or_else(|| "-".to_string())
+                );
+            }
+        }
+    }
+    if !any_difference {
+        println!("    (none)");
+    }
+
+    let peak = |runs: &[RunEvents]| runs.iter().filter_map(|run| run.peak_memory_bytes).max();
+    println!(
+        "\n  Peak memory: A {}  B {}\n",
+        format_memory(peak(runs_a)),
+        format_memory(peak(runs_b))
+    );
+}
@@ -30,6 +30,7 @@ static ORIGINAL_RULES_COUNT: Lazy<RwLock<usize>> = Lazy::new(|| RwLock::new(0));
 static ON_SPACE_VIEWS: Lazy<RwLock<Vec<View>>> = Lazy::new(|| RwLock::new(vec![]));
 
 static EXEC_ROOT: Lazy<RwLock<Option<PathBuf>>> = Lazy::new(|| RwLock::new(None));
+static SEED: Lazy<RwLock<Option<u64>>> = Lazy::new(|| RwLock::new(None));
 
 /// **Set functions**
 pub fn set_views(views: Vec<View>) {
@@ -218,16 +219,50 @@ pub fn get_total_rules_count(new_rules_len: usize) -> usize {
     get_original_rules_count() + new_rules_len
 }
 
+/// Deterministic mode: set by `--seed`, recorded in the execution manifest
+pub fn set_seed(seed: u64) {
+    *SEED.write().unwrap() = Some(seed);
+}
+
+pub fn get_seed() -> Option<u64> {
+    *SEED.read().unwrap()
+}
+
+/// Order of tied items, such as equivalent algorithms of which the first is kept: the
+/// index itself, or with `--seed` a permutation of the indices drawn from the seed
+pub fn tie_break_key(index: usize) -> u64 {
+    match get_seed() {
+        None => index as u64,
+        Some(seed) => {
+            // SplitMix64 finalizer: a bijection, so distinct indices keep distinct keys
+            let mut z = seed ^ index as u64;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+    }
+}
+
+/// `results/Execution_<timestamp>`, or `results/Execution_seed-<seed>` in deterministic
+/// mode (`_2`, `_3`, ... when it exists, so reruns can be compared)
 pub fn init_execution_root() -> PathBuf {
     let mut guard = EXEC_ROOT.write().unwrap();
 
     if guard.is_none() {
-        let timestamp = Local::now()
-            .format("Execution_%Y-%m-%d_%H-%M-%S")
-            .to_string();
+        let name = match get_seed() {
+            Some(seed) => format!("Execution_seed-{}", seed),
+            None => Local::now()
+                .format("Execution_%Y-%m-%d_%H-%M-%S")
+                .to_string(),
+        };
 
         // Place inside results folder
-        let folder = PathBuf::from("results").join(timestamp);
+        let mut folder = PathBuf::from("results").join(&name);
+        let mut run = 1;
+        while get_seed().is_some() && folder.exists() {
+            run += 1;
+            folder = PathBuf::from("results").join(format!("{}_{}", name, run));
+        }
         fs::create_dir_all(&folder).expect("Failed to create execution directory");
         *guard = Some(folder);
     }
@@ -243,6 +278,11 @@ pub fn get_execution_root() -> PathBuf {
         .expect("Execution root not initialized. Call init_execution_root() first.")
 }
 
+/// Execution root if `init_execution_root()` was called (generation runs only).
+pub fn try_get_execution_root() -> Option<PathBuf> {
+    EXEC_ROOT.read().unwrap().clone()
+}
+
 /// Convenience: return execution root as String for APIs expecting &str.
 pub fn get_execution_root_str() -> String {
     get_execution_root().to_string_lossy().into_owned()
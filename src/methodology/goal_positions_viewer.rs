@@ -1,6 +1,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::modules::viewer_assets::viewer_styles;
+
 /// Generates an HTML page with embedded position data JSON for goal/target positions
 pub fn generate_goal_positions_viewer(
     positions: &[Vec<Vec<(char, i16, i16)>>],
@@ -43,6 +45,7 @@ fn generate_html_template(
     goal_index: usize,
     target_index: usize,
 ) -> String {
+    let bundled_css = viewer_styles();
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -50,20 +53,7 @@ fn generate_html_template(
     <meta charset="UTF-8" />
     <meta name="viewport" content="width=device-width, initial-scale=1.0" />
     <title>Position Viewer - Goal {} Target {}</title>
-    <link
-      href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.0/dist/css/bootstrap.min.css"
-      rel="stylesheet"
-    />
-    <link
-      rel="stylesheet"
-      href="https://cdn.jsdelivr.net/npm/bootstrap-icons@1.10.5/font/bootstrap-icons.css"
-    />
-    <link rel="preconnect" href="https://fonts.googleapis.com" />
-    <link rel="preconnect" href="https://fonts.gstatic.com" crossorigin />
-    <link
-      href="https://fonts.googleapis.com/css2?family=Inter:wght@400;500;700&display=swap"
-      rel="stylesheet"
-    />
+    {bundled_css}
     <style>
       body {{
         background-color: #f0f2f5;
@@ -1,6 +1,7 @@
 use crate::methodology::configuration::CONFIG;
 use crate::methodology::globals::get_execution_root_str;
 use crate::methodology::goal_target_result::GoalTargetResult;
+use crate::modules::viewer_assets::viewer_styles;
 use std::fs;
 use std::path::Path;
 
@@ -37,6 +38,7 @@ pub fn generate_goals_viewer(goals_targets_details: &[Vec<GoalTargetResult>]) {
 fn generate_goals_viewer_html(configs_json: &str, result_json: &str) -> String {
     // Wrap the entire template in the format! macro
     // Inject configs_json and result_json directly as JS objects/arrays
+    let bundled_css = viewer_styles();
     format!(
         r##"<!DOCTYPE html>
 <html lang="en">
@@ -44,10 +46,7 @@ fn generate_goals_viewer_html(configs_json: &str, result_json: &str) -> String {
         <meta charset="UTF-8" />
         <meta name="viewport" content="width=device-width, initial-scale=1.0" />
         <title>Multi-Robot Sim Editor (Multi-Grid)</title>
-        <link
-            href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.0/dist/css/bootstrap.min.css"
-            rel="stylesheet"
-        />
+        {bundled_css}
         <style>
             /* --- Basic Layout & Body --- */
             body {{
@@ -35,7 +35,7 @@ use crate::{
         config::Config,
         direction::{self, rotate_direction},
         draft_rules::{self, DraftRule},
-        execution_logger::log_note,
+        execution_logger::{log_note, start_stage},
         full_rule::print_full_rule,
         opacity_validator::generate_group_views_by_opacity,
         parallel_rules::{
@@ -81,45 +81,53 @@ pub fn methodology() -> usize {
         let on_space_rules = find_on_space_rules(&get_views());
         set_on_space_views(on_space_rules);
 
-        let time_start = Instant::now();
+        let stage = start_stage("generate_views");
         let colors: Vec<char> = get_colors(&get_all_color_letters(), *get_number_of_colors());
-        let mut generated_views = generate_views(&colors);
+        let mut generated_views = generate_views(
+            &colors,
+            *get_number_of_robots(),
+            *get_visibility(),
+            CONFIG.opacity,
+        );
         println!(
             "before remove existed Views len = {} ",
             generated_views.len()
         );
-        log_note(&format!(
+        let note = format!(
             "{} views generated in {}",
             generated_views.len(),
-            format_elapsed_time(time_start)
-        ));
-        let time_start = Instant::now();
+            format_elapsed_time(stage.start)
+        );
+        stage.finish_with_note(&[("views", generated_views.len())], &note);
+        let stage = start_stage("remove_existing_views");
         remove_existed_views(&mut generated_views, &get_on_space_views());
         println!(
             "after remove existed Views len = {} ",
             generated_views.len()
         );
-        log_note(&format!(
+        let note = format!(
             "{} views generated after removing existing records in {}",
             generated_views.len(),
-            format_elapsed_time(time_start)
-        ));
+            format_elapsed_time(stage.start)
+        );
+        stage.finish_with_note(&[("views", generated_views.len())], &note);
 
         add_views(&generated_views);
 
 
-        let time_start = Instant::now();
+        let stage = start_stage("generate_rules");
         let mut generated_rules = generate_rules(&colors, &get_views(), get_original_views_count());
         if CONFIG.opacity {
 
             remove_incompatible_rules_via_opacity(&get_rules(), &mut generated_rules);
         }
         add_rules(&generated_rules);
-        log_note(&format!(
+        let note = format!(
             "{} rules successfully generated in {}",
             generated_rules.len(),
-            format_elapsed_time(time_start)
-        ));
+            format_elapsed_time(stage.start)
+        );
+        stage.finish_with_note(&[("rules", generated_rules.len())], &note);
 
         /*/ for (i, rule) in get_rules().iter().enumerate() {
             println!("----------------------Rule {i}--------------------------");
@@ -143,7 +151,7 @@ pub fn methodology() -> usize {
         println!("No algorithm found at index {}", index);
     }
 
-    let time_start = Instant::now();
+    let stage = start_stage("generate_parallel_rules");
     let list_of_parallel_rules: Vec<ParallelRules> = parallel_rules_generator();
     set_parallel_rules(list_of_parallel_rules.clone());
     /*  print_parallel_rules(
@@ -155,23 +163,25 @@ pub fn methodology() -> usize {
     );*/
 
 
-    log_note(&format!(
+    let note = format!(
         "{} parallel rules successfully generated in {}",
         list_of_parallel_rules.len(),
-        format_elapsed_time(time_start)
-    ));
+        format_elapsed_time(stage.start)
+    );
+    stage.finish_with_note(&[("parallel_rules", list_of_parallel_rules.len())], &note);
 
     println!("Validating parallel rules...");
-    let validation_start = Instant::now();
+    let stage = start_stage("validate_parallel_rules");
     if !validate_parallel_rules(&list_of_parallel_rules) {
         println!("Error: Invalid parallel rules detected.");
         exit(1);
     }
     println!("All parallel rules are valid.");
-    log_note(&format!(
+    let note = format!(
         "Parallel rules validated in {}",
-        format_elapsed_time(validation_start)
-    ));
+        format_elapsed_time(stage.start)
+    );
+    stage.finish_with_note(&[("parallel_rules", list_of_parallel_rules.len())], &note);
 
     if CONFIG.opacity {
         let (compressed_rules, removed_rules, compressed_views, original_rules_count) =
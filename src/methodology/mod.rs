@@ -1,5 +1,7 @@
 pub mod cache;
 pub mod configuration;
+pub mod execution_index;
+pub mod execution_report;
 pub mod globals;
 pub mod goal_positions_viewer;
 pub mod goal_target_result;
@@ -9,5 +11,6 @@ pub mod parallel_rules;
 pub mod parallel_rules_viewer;
 pub mod rules_viewer;
 pub mod simulator;
+pub mod streaming_combiner;
 pub mod switching_colors_validator;
 pub mod view;
@@ -11,6 +11,7 @@ use crate::{
         parallel_rules::{
             self, extract_ending_positions, extract_starting_positions, ParallelRules,
         },
+        viewer_assets::viewer_styles,
     },
 };
 
@@ -93,6 +94,7 @@ pub fn generate_parallel_rules_html(
 
     let json_data = serde_json::to_string(collection).expect("Failed to serialize");
 
+    let bundled_css = viewer_styles();
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -100,7 +102,7 @@ pub fn generate_parallel_rules_html(
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Parallel Rules Viewer</title>
-    <link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.2/dist/css/bootstrap.min.css" rel="stylesheet">
+    {bundled_css}
     <style>
         * {{
             margin: 0;
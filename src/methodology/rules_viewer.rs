@@ -5,7 +5,7 @@ use crate::{
         get_number_of_colors, get_number_of_robots, get_original_rules_count, get_rules, get_views,
         get_visibility,
     },
-    modules::final_rule::FinalRule,
+    modules::{final_rule::FinalRule, viewer_assets::viewer_styles},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +56,7 @@ pub fn generate_rules_html(collection: &RulesCollection, output_path: &str) {
 
     let json_data = serde_json::to_string(collection).expect("Failed to serialize");
 
+    let bundled_css = viewer_styles();
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -63,7 +64,7 @@ pub fn generate_rules_html(collection: &RulesCollection, output_path: &str) {
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Rules Viewer</title>
-    <link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.2/dist/css/bootstrap.min.css" rel="stylesheet">
+    {bundled_css}
     <style>
         * {{
             margin: 0;
@@ -6,11 +6,15 @@ use crate::methodology::configuration::COMBINATION_MODE;
 use crate::methodology::globals::{
     are_in_same_opacity_group, get_all_color_letters, get_execution_root_str, get_number_of_colors,
     get_opacity_group_id, get_opacity_group_lookup, get_original_rules_count, get_parallel_rules,
+    tie_break_key,
 };
 use crate::methodology::goal_positions_viewer::generate_goal_positions_viewer;
 use crate::methodology::goal_target_result::GoalTargetResult;
 use crate::methodology::goals_viewer::generate_goals_viewer;
 use crate::methodology::parallel_rules::{self, calculate_activation_counts};
+use crate::methodology::streaming_combiner::{
+    combine_streaming_by_activation, stream_combinations, LevelBuckets, LEVEL_BUCKETS_FOLDER,
+};
 use crate::methodology::switching_colors_validator::remove_duplicates_by_color_switches;
 use crate::modules::algorithm;
 use crate::modules::algorithm_experiments_modules::algorithm_experiments::AlgorithmExperiments;
@@ -25,6 +29,7 @@ use crate::modules::parallel_rules::{
 };
 use crate::modules::progress_helper::ProgressHelper;
 use crate::modules::rule::Rule;
+use crate::modules::streaming_combination::StreamingCombination;
 
 use crate::modules::time_helper::format_elapsed_time;
 use crate::validation::initial_config_generator::generate_initial_configs;
@@ -34,7 +39,7 @@ use crate::{
     modules::{
         color,
         direction::Direction,
-        execution_logger::log_note,
+        execution_logger::{log_note, start_stage},
         folder_generator::FolderGenerator,
         parallel_rules::ParallelRules,
         rule,
@@ -49,7 +54,7 @@ use core::time;
 use fxhash::FxHasher;
 use itertools::{iproduct, Group, Unique};
 use rayon::{prelude::*, range, vec};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::{self, File};
 use std::hash::{self, Hash, Hasher};
 use std::io::{BufWriter, Write};
@@ -75,9 +80,10 @@ pub fn run_simulation() -> usize {
     let mut list_of_executions: Vec<Vec<Vec<usize>>> = vec![];
 
     let mut goals_targets_details: Vec<Vec<GoalTargetResult>> = vec![];
-    let goals_start = Instant::now(); // Start timing
+    let goals_stage = start_stage("simulate_goals");
     for (i, config) in simulation_configs.iter().enumerate() {
-        let goal_start = Instant::now(); // Start timing
+        let goal_stage = start_stage("simulate_goal");
+        let goal_start = goal_stage.start;
         let (temp_list_executions, temp_list_positions) = simulation(
             i + 1,
             &config.initial_positions,
@@ -126,13 +132,21 @@ pub fn run_simulation() -> usize {
             ));
         }
 
+        goal_stage.finish(&[
+            ("targets", targets_details.len()),
+            (
+                "executions",
+                targets_details.iter().map(|target| target.execution_count).sum(),
+            ),
+        ]);
         goals_targets_details.push(targets_details);
     }
 
-    log_note(&format!(
+    let note = format!(
         "All goals simulated in {} sec",
-        format_elapsed_time(goals_start)
-    ));
+        format_elapsed_time(goals_stage.start)
+    );
+    goals_stage.finish_with_note(&[("goals", simulation_configs.len())], &note);
     generate_goals_viewer(&goals_targets_details);
 
     let mut validated_global_algos: Vec<Vec<usize>> = Vec::new();
@@ -142,17 +156,28 @@ pub fn run_simulation() -> usize {
             println!();
             // Mode 1: Generate all possible algorithms without filtering
             println!("🔄 Generation Mode: ALL - Generating all possible algorithms");
-            validated_global_algos = combine(&list_of_executions, &parallel_rules);
-            let validated_global_algos: Vec<Vec<usize>> =
-                sort_validated_algorithms(validated_global_algos);
-            println!("{} global algos sorted", validated_global_algos.len());
-
-            // 2. Remove duplicate rules within each algorithm
             let (filtered_algos, original_rules_indices_cleaned) =
-                convert_and_deduplicate_rules_in_each_algorithm(
-                    &validated_global_algos,
-                    CONFIG.opacity,
-                );
+                match CONFIG.streaming_combination {
+                    // The streamed algorithms are cleaned one at a time, never all held
+                    Some(streaming) => combine_and_clean_streaming(
+                        &list_of_executions,
+                        &parallel_rules,
+                        streaming,
+                        CONFIG.opacity,
+                    ),
+                    None => {
+                        validated_global_algos = combine(&list_of_executions, &parallel_rules);
+                        let validated_global_algos: Vec<Vec<usize>> =
+                            sort_validated_algorithms(validated_global_algos);
+                        println!("{} global algos sorted", validated_global_algos.len());
+
+                        // 2. Remove duplicate rules within each algorithm
+                        convert_and_deduplicate_rules_in_each_algorithm(
+                            &validated_global_algos,
+                            CONFIG.opacity,
+                        )
+                    }
+                };
 
             let (unique_algos, removed_indices, hashed, runs) = if CONFIG.opacity {
                 remove_duplicates_indexed_simple_fast(filtered_algos)
@@ -176,76 +201,68 @@ pub fn run_simulation() -> usize {
                 log_hash_stats(&hashed, &runs, &cleaned_algos, &global_folder);
             }
             // Validate single folder
-            let validation_start = Instant::now();
-            validate_single_folder(&global_folder);
-            log_note(&format!(
+            let stage = start_stage("validation");
+            let algorithm_snapshot = validate_single_folder(&global_folder);
+            let note = format!(
                 "Validation completed in {}",
-                format_elapsed_time(validation_start)
-            ));
+                format_elapsed_time(stage.start)
+            );
+            stage.finish_with_note(
+                &[
+                    ("algorithms", cleaned_algos.len()),
+                    ("validated", algorithm_snapshot.validated_ld.len()),
+                ],
+                &note,
+            );
 
             // Classification
-            let classification_start = Instant::now();
+            let stage = start_stage("classification");
             classify(
                 format!("{}/Algos", &get_execution_root_str()).as_str(),
                 global_folder.as_str(),
             );
 
-            log_note(&format!(
+            let note = format!(
                 "Classification completed in {}",
-                format_elapsed_time(classification_start)
-            ));
+                format_elapsed_time(stage.start)
+            );
+            stage.finish_with_note(&[], &note);
 
             cleaned_algos.len()
         }
 
         GenerationMode::ProgressiveValidationByLevels(max_levels) => {
-            let prograsive_start_time = Instant::now();
+            let progressive_stage = start_stage("progressive_validation");
             //generate all possible algorithms without filtering
             println!();
             println!(
                 "🔄 Generation Mode: ProgressiveValidation (max {} levels) ",
                 max_levels
             );
-            validated_global_algos = combine(&list_of_executions, &parallel_rules);
-
-            //calculate activations for all algorithms
-            let list_of_activations = validated_global_algos
-                .iter()
-                .map(|algo| calculate_total_activation(algo, &parallel_rules))
-                .collect::<Vec<usize>>();
-
-            //Group algorithm indices by activation level
-            let mut activation_groups: HashMap<usize, Vec<usize>> = HashMap::new();
-            let mut max_activation = 0;
-
-            for (algo_idx, activation) in list_of_activations.iter().enumerate() {
-                activation_groups
-                    .entry(*activation)
-                    .or_default()
-                    .push(algo_idx);
-            }
-            //Sort each group's algorithm indices
-            for indices in activation_groups.values_mut() {
-                indices.sort_unstable();
-            }
-
-            //Convert to sorted vector of (activation, indices)
-            let mut result: Vec<(usize, Vec<usize>)> = activation_groups.into_iter().collect();
-
-            max_activation = result
-                .last()
-                .map(|(k, _)| *k)
-                .unwrap_or(0)
-                .to_string()
-                .len();
+            // Levels are validated one at a time: only the level being processed is
+            // held in memory (the streaming path keeps the others on disk)
+            let mut levels = match CONFIG.streaming_combination {
+                Some(streaming) => ActivationLevels::Spilled(combine_streaming_by_activation(
+                    &list_of_executions,
+                    &parallel_rules,
+                    streaming,
+                    max_levels,
+                    Path::new(&format!("{}/{}", get_execution_root_str(), LEVEL_BUCKETS_FOLDER)),
+                )),
+                None => ActivationLevels::group(
+                    combine(&list_of_executions, &parallel_rules),
+                    &parallel_rules,
+                ),
+            };
+            let level_keys = levels.levels();
 
-            // Sort by activation level (ascending)
-            result.sort_unstable_by_key(|(activation, _)| *activation);
+            // The folder names are padded to the width of the highest level
+            let max_activation = level_keys.last().copied().unwrap_or(0).to_string().len();
 
             log_note(&format!(
                 "{} levels found, activation levels: {:?}",
-                result.len(),
-                result.iter().map(|(k, _)| *k).collect::<Vec<usize>>()
+                level_keys.len(),
+                level_keys
             ));
 
             println!("\n╔═══════════════════════════════════════════════════════╗");
@@ -258,28 +275,20 @@ pub fn run_simulation() -> usize {
 
             let mut algorithm_index = 0;
             let mut list_of_validation_results: Vec<String> = vec![];
-            for (i, (activation_level, algorithm_set)) in result.iter().enumerate() {
-                let _start_time = Instant::now();
+            for (i, activation_level) in level_keys.iter().enumerate() {
+                let level_stage = start_stage("activation_level");
+                //reoder algorithms by content to ensure consistent processing
+                let mut algos = levels.take(*activation_level);
+                algos.sort();
+                let level_size = algos.len();
                 println!("\n┌─────────────────────────────────────────────────────┐");
                 println!(
                     "│ 📊 Activation Level: {} ({} algorithms)",
                     activation_level,
-                    algorithm_set.len()
+                    level_size
                 );
                 println!("└─────────────────────────────────────────────────────┘");
 
-                //reoder algorithms by their original indices to ensure consistent processing
-                let mut ordered_indices = algorithm_set.clone(); // copy the indices
-                ordered_indices
-                    .sort_by(|&a, &b| validated_global_algos[a].cmp(&validated_global_algos[b]));
-
-                //
-
-                let mut algos: Vec<Vec<usize>> = ordered_indices
-                    .iter()
-                    .map(|&idx| validated_global_algos[idx].clone())
-                    .collect();
-
                 let (filtered_algos, original_rules_indices_cleaned) =
                     convert_and_deduplicate_rules_in_each_algorithm(&algos, CONFIG.opacity);
 
@@ -342,12 +351,19 @@ pub fn run_simulation() -> usize {
                 // Validate single folder
                 //try to add in th lognote the number of algorithms validated
                 let algorithm_snapshot = validate_single_folder(folder_path.as_str());
-                log_note(&format!(
+                let note = format!(
                     "Activation level {} processed in {} : {} algorithms validated",
                     activation_level,
-                    format_elapsed_time(_start_time),
+                    format_elapsed_time(level_stage.start),
                     algorithm_snapshot.validated_ld.len()
-                ));
+                );
+                level_stage.finish_with_note(
+                    &[
+                        ("algorithms", level_size),
+                        ("validated", algorithm_snapshot.validated_ld.len()),
+                    ],
+                    &note,
+                );
 
                 if max_levels > 0 && i + 1 >= max_levels {
                     println!(
@@ -367,10 +383,11 @@ pub fn run_simulation() -> usize {
             println!("║       PROGRESSIVE VALIDATION COMPLETED                ║");
             println!("╚═══════════════════════════════════════════════════════╝\n");
 
-            log_note(&format!(
+            let note = format!(
                 "Progressive validation completed in {}",
-                format_elapsed_time(prograsive_start_time)
-            ));
+                format_elapsed_time(progressive_stage.start)
+            );
+            progressive_stage.finish_with_note(&[("levels", level_keys.len())], &note);
             0
         }
     }
@@ -853,7 +870,7 @@ fn are_executions_consistent(
 }
 
 /// Merge two executions (vectors of parallel rule indices) into one
-fn merge_executions(
+pub fn merge_executions(
     execution_a: &[usize],
     execution_b: &[usize],
     list_of_parallel_rules: &[ParallelRules],
@@ -1007,43 +1024,7 @@ fn generate_web_algo(
     views: &[View],
     original_rules_indices_cleaned: &[usize],
 ) {
-    let template = r"****** OPTIONS ******
-version: 1
-walls:
-  - - {wall_x0}
-    - {wall_y0}
-    - 0
-  - - {wall_x1}
-    - {wall_y1}
-    - 2
-chirality: true
-visibilityRange: {visibility}
-colors:
-{colors}
-dimension: 2
-
-****** INITIAL CONFIGURATIONS ******
-{initial_configurations}
-
-****** RULES ******
-@alias X {alias_x}
-
-{rules}
-";
-
-    let mut generator = WebAlgoGenerator::new(&filename, dir, template);
-    generator.set("visibility", &get_visibility().to_string());
-    // Set wall positions from constants
-    generator.set("wall_x0", &CONFIG.web_algo_walls[0][0].to_string());
-    generator.set("wall_y0", &CONFIG.web_algo_walls[0][1].to_string());
-    generator.set("wall_x1", &CONFIG.web_algo_walls[1][0].to_string());
-    generator.set("wall_y1", &CONFIG.web_algo_walls[1][1].to_string());
-    generator.set("colors", &CONFIG.web_algo_colors);
-    generator.set(
-        "initial_configurations",
-        &CONFIG.web_algo_initial_configuration,
-    );
-    generator.set("alias_x", &build_alias_x());
+    let mut generator = WebAlgoGenerator::with_config(&filename, dir, *get_visibility());
 
     for &rule_id in new_rules {
         generator.add_rule(&format!("# New rule: {}", rule_id));
@@ -1137,7 +1118,7 @@ fn generate_yaml_string_rule(
     rule_string
 }
 
-fn generate_web_algo_string_rule(
+pub fn generate_web_algo_string_rule(
     points: &[(char, i16, i16)],
     dir: Direction,
     color: char,
@@ -1179,14 +1160,7 @@ fn generate_web_algo_string_rule(
 
         // Append movement rule at the end of the visibility index row
         if i == visibility as usize {
-            let dir_str = match dir {
-                Direction::Up => "front",
-                Direction::Down => "back",
-                Direction::Left => "left",
-                Direction::Right => "right",
-                Direction::Idle => "idle",
-            };
-            rule_string.push_str(&format!(" -> {}, {}", dir_str, color));
+            rule_string.push_str(&format!(" -> {}, {}", dir.as_web_algo_str(), color));
         }
         rule_string.push('\n');
     }
@@ -1259,7 +1233,8 @@ fn combine(
     list_of_executions: &Vec<Vec<Vec<usize>>>,
     list_of_parallel_rules: &[ParallelRules],
 ) -> Vec<Vec<usize>> {
-    let combination_start: Instant = Instant::now(); // Start timing
+    let stage = start_stage("combination");
+    let combination_start = stage.start;
     let mut global_algos: Vec<Vec<usize>> = Vec::new();
 
     match COMBINATION_MODE {
@@ -1296,14 +1271,79 @@ fn combine(
         }
     }
 
-    log_note(&format!(
-        "combinaison in {}",
-        format_elapsed_time(combination_start),
-    ));
+    let note = format!("combinaison in {}", format_elapsed_time(combination_start));
+    stage.finish_with_note(&[("global_algos", global_algos.len())], &note);
     // manager.write_executions(&global_algos, "global_algos.json");
     global_algos
 }
 
+/// `combine` for `streaming`: each global algorithm of the stream is reduced to its
+/// cleaned rule indices as soon as it is produced, so only the cleaned algorithms are
+/// kept. Returns them sorted, with the cleaned original rule indices.
+fn combine_and_clean_streaming(
+    list_of_executions: &[Vec<Vec<usize>>],
+    list_of_parallel_rules: &[ParallelRules],
+    streaming: StreamingCombination,
+    opacity: bool,
+) -> (Vec<Vec<usize>>, Vec<usize>) {
+    let stage = start_stage("combination");
+    let original_rules_indices_cleaned = clean_original_rules_indices(opacity);
+
+    let mut cleaned_algos: Vec<Vec<usize>> =
+        stream_combinations(list_of_executions, list_of_parallel_rules, streaming)
+            .map(|mut algorithm| {
+                algorithm.sort();
+                clean_rules_of_algorithm(&algorithm, &original_rules_indices_cleaned, opacity)
+            })
+            .collect();
+    if cleaned_algos.is_empty() {
+        panic!("❌ No valid combination of the goals!\n💡 Edit the goals and try again");
+    }
+    cleaned_algos.sort();
+
+    let note = format!(
+        "Streaming combination in {}: {} global algos found!",
+        format_elapsed_time(stage.start),
+        cleaned_algos.len()
+    );
+    stage.finish_with_note(&[("global_algos", cleaned_algos.len())], &note);
+    (cleaned_algos, original_rules_indices_cleaned)
+}
+
+/// Global algorithms grouped by activation level, handed out one level at a time
+enum ActivationLevels {
+    InMemory(BTreeMap<usize, Vec<Vec<usize>>>),
+    Spilled(LevelBuckets),
+}
+
+impl ActivationLevels {
+    fn group(global_algos: Vec<Vec<usize>>, parallel_rules: &[ParallelRules]) -> Self {
+        let mut levels: BTreeMap<usize, Vec<Vec<usize>>> = BTreeMap::new();
+        for algo in global_algos {
+            let activation = calculate_total_activation(&algo, parallel_rules);
+            levels.entry(activation).or_default().push(algo);
+        }
+        ActivationLevels::InMemory(levels)
+    }
+
+    /// Activation levels, ascending
+    fn levels(&self) -> Vec<usize> {
+        match self {
+            ActivationLevels::InMemory(levels) => levels.keys().copied().collect(),
+            ActivationLevels::Spilled(buckets) => buckets.levels(),
+        }
+    }
+
+    fn take(&mut self, level: usize) -> Vec<Vec<usize>> {
+        match self {
+            ActivationLevels::InMemory(levels) => levels.remove(&level).unwrap_or_default(),
+            ActivationLevels::Spilled(buckets) => buckets.take(level).unwrap_or_else(|e| {
+                panic!("❌ Failed to read activation level {}: {}", level, e);
+            }),
+        }
+    }
+}
+
 fn sort_validated_algorithms(validated_global_algos: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
     // Convert HashSet to Vec for sorting
     let mut validated_global_algos_vec: Vec<Vec<usize>> =
@@ -1610,45 +1650,19 @@ pub fn remove_duplicate_rules_in_algorithm(
     rules_of_algorithm
 }
 
-fn build_alias_x() -> String {
-    let mut alias = vec!['.', 'W', 'O']; // fixed ones
-    let num_colors = get_number_of_colors();
-
-    // take only as many as needed from ALL_COLOR_LETTERS
-    alias.extend_from_slice(
-        &CONFIG.all_color_letters[..num_colors.min(CONFIG.all_color_letters.len())],
-    );
-
-    // turn into string like "{.,W,O,F,L,G}"
-    let joined: String = alias
-        .iter()
-        .map(|c| c.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
-    format!("{{{}}}", joined)
-}
-
 fn convert_and_deduplicate_rules_in_each_algorithm(
     validated_algos: &[Vec<usize>],
     opacity: bool,
 ) -> (Vec<Vec<usize>>, Vec<usize>) {
     let progress = ProgressHelper::new(validated_algos.len() as u64, "Cleaning duplicate rules");
 
-    let original_rules_indices: Vec<usize> = (0..get_original_rules_count()).collect();
-    let original_rules_indices_cleaned =
-        remove_duplicate_rules(original_rules_indices, &get_rules(), opacity);
+    let original_rules_indices_cleaned = clean_original_rules_indices(opacity);
 
     let result: Vec<Vec<usize>> = validated_algos
         .par_iter()
         .map(|algorithm| {
-            let new_rule_indices = extract_rules(&algorithm.clone(), &get_parallel_rules());
-
-            let cleaned_rules = remove_duplicate_rules_in_algorithm(
-                new_rule_indices,
-                &original_rules_indices_cleaned,
-                &get_rules(),
-                opacity,
-            );
+            let cleaned_rules =
+                clean_rules_of_algorithm(algorithm, &original_rules_indices_cleaned, opacity);
 
             progress.inc();
             cleaned_rules
@@ -1659,6 +1673,27 @@ fn convert_and_deduplicate_rules_in_each_algorithm(
     (result, original_rules_indices_cleaned)
 }
 
+/// Indices of the original rules without their duplicates
+fn clean_original_rules_indices(opacity: bool) -> Vec<usize> {
+    let original_rules_indices: Vec<usize> = (0..get_original_rules_count()).collect();
+    remove_duplicate_rules(original_rules_indices, &get_rules(), opacity)
+}
+
+/// Rule indices of a global algorithm (parallel rule indices) without duplicate rules
+fn clean_rules_of_algorithm(
+    algorithm: &[usize],
+    original_rules_indices_cleaned: &[usize],
+    opacity: bool,
+) -> Vec<usize> {
+    let new_rule_indices = extract_rules(algorithm, &get_parallel_rules());
+    remove_duplicate_rules_in_algorithm(
+        new_rule_indices,
+        original_rules_indices_cleaned,
+        &get_rules(),
+        opacity,
+    )
+}
+
 fn generate_all_algorithms_files(
     filtered_algos: &[Vec<usize>],
     global_folder: &str,
@@ -1867,8 +1902,9 @@ pub fn remove_duplicates_indexed_simple_fast(
         })
         .collect();
 
-    // 2) Parallel sort by hash so equal keys are contiguous
-    hashed.par_sort_unstable_by_key(|&(h, _, _)| h);
+    // 2) Parallel sort by hash so equal keys are contiguous; within a run the first
+    //    of equivalent algorithms is kept, so ties follow `tie_break_key`
+    hashed.par_sort_unstable_by_key(|&(h, i, _)| (h, tie_break_key(i)));
 
     // 3) Identify contiguous runs of equal hashes
     let mut runs = Vec::new();
@@ -0,0 +1,254 @@
+// ============================================================================
+// STREAMING COMBINER
+// ============================================================================
+//
+// Enumerates global algorithms (one compatible execution per goal) lazily.
+//
+// `combine` in Sequential mode keeps every partial algorithm after each goal,
+// so the intermediate cross products are held in memory even when most of
+// them die at a later goal. The streaming combiner walks the goals depth
+// first instead: only the current path (one merged execution per goal) is
+// kept, and each complete algorithm is handed to the caller as soon as it is
+// found. The set of algorithms is the same as Sequential mode, in DFS order.
+//
+// A level is only complete once the whole stream has been walked (any later
+// algorithm may have any activation), so `combine_streaming_by_activation`
+// appends each algorithm to the file of its level (`LevelBuckets`) as it is
+// produced. Progressive validation then loads one level at a time: peak
+// memory is the largest level kept, not the whole algorithm set.
+//
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    methodology::simulator::merge_executions,
+    modules::{
+        execution_logger::start_stage,
+        parallel_rules::{calculate_total_activation, ParallelRules},
+        streaming_combination::StreamingCombination,
+        time_helper::format_elapsed_time,
+    },
+};
+
+use super::{configuration::CONFIG, globals::get_rules};
+
+/// Depth-first iterator over the compatible execution tuples of all goals
+pub struct StreamingCombiner<'a> {
+    list_of_executions: &'a [Vec<Vec<usize>>],
+    list_of_parallel_rules: &'a [ParallelRules],
+    /// `partials[d]` is the merge of the executions chosen for the first `d` goals
+    partials: Vec<Vec<usize>>,
+    /// Next execution to try for each goal on the current path
+    cursors: Vec<usize>,
+}
+
+impl<'a> StreamingCombiner<'a> {
+    pub fn new(
+        list_of_executions: &'a [Vec<Vec<usize>>],
+        list_of_parallel_rules: &'a [ParallelRules],
+    ) -> Self {
+        StreamingCombiner {
+            list_of_executions,
+            list_of_parallel_rules,
+            partials: vec![Vec::new()],
+            cursors: vec![0; list_of_executions.len()],
+        }
+    }
+}
+
+impl Iterator for StreamingCombiner<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let depth = self.partials.len().checked_sub(1)?;
+
+            if depth == self.list_of_executions.len() {
+                // Every goal has an execution: the path is a complete algorithm
+                return self.partials.pop();
+            }
+
+            let cursor = self.cursors[depth];
+            if cursor >= self.list_of_executions[depth].len() {
+                // Goal exhausted on this path: backtrack
+                self.cursors[depth] = 0;
+                self.partials.pop();
+                if depth == 0 {
+                    return None;
+                }
+                continue;
+            }
+            self.cursors[depth] += 1;
+
+            let execution = &self.list_of_executions[depth][cursor];
+            let merged = if depth == 0 {
+                execution.clone()
+            } else {
+                merge_executions(
+                    &self.partials[depth],
+                    execution,
+                    self.list_of_parallel_rules,
+                    &get_rules(),
+                    CONFIG.opacity,
+                )
+            };
+            if !merged.is_empty() {
+                self.partials.push(merged);
+            }
+        }
+    }
+}
+
+/// Applies the sampling and the cap of `streaming` to the combiner
+pub fn stream_combinations<'a>(
+    list_of_executions: &'a [Vec<Vec<usize>>],
+    list_of_parallel_rules: &'a [ParallelRules],
+    streaming: StreamingCombination,
+) -> impl Iterator<Item = Vec<usize>> + 'a {
+    StreamingCombiner::new(list_of_executions, list_of_parallel_rules)
+        .step_by(streaming.sample_every.max(1))
+        .take(streaming.max_algorithms.unwrap_or(usize::MAX))
+}
+
+/// Folder of the execution root holding the level buckets while they are validated
+pub const LEVEL_BUCKETS_FOLDER: &str = "_combination_levels";
+
+/// Algorithms of each activation level, one file per level (one algorithm per
+/// line) under `folder`. The folder is removed when the buckets are dropped.
+pub struct LevelBuckets {
+    folder: PathBuf,
+    /// Algorithms kept per level
+    counts: BTreeMap<usize, usize>,
+    writers: BTreeMap<usize, BufWriter<File>>,
+}
+
+impl LevelBuckets {
+    pub fn create(folder: &Path) -> io::Result<Self> {
+        fs::create_dir_all(folder)?;
+        Ok(LevelBuckets {
+            folder: folder.to_path_buf(),
+            counts: BTreeMap::new(),
+            writers: BTreeMap::new(),
+        })
+    }
+
+    fn level_path(&self, level: usize) -> PathBuf {
+        self.folder.join(format!("level_{}.txt", level))
+    }
+
+    /// Activation levels kept, ascending
+    pub fn levels(&self) -> Vec<usize> {
+        self.counts.keys().copied().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn push(&mut self, level: usize, algo: &[usize]) -> io::Result<()> {
+        if !self.writers.contains_key(&level) {
+            let file = File::create(self.level_path(level))?;
+            self.writers.insert(level, BufWriter::new(file));
+        }
+        let line = algo
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(self.writers.get_mut(&level).unwrap(), "{}", line)?;
+        *self.counts.entry(level).or_insert(0) += 1;
+        Ok(())
+    }
+
+    /// Drops every algorithm of `level`
+    pub fn discard(&mut self, level: usize) {
+        self.counts.remove(&level);
+        if self.writers.remove(&level).is_some() {
+            let _ = fs::remove_file(self.level_path(level));
+        }
+    }
+
+    /// Reads back the algorithms of `level` and removes its file
+    pub fn take(&mut self, level: usize) -> io::Result<Vec<Vec<usize>>> {
+        self.counts.remove(&level);
+        let Some(mut writer) = self.writers.remove(&level) else {
+            return Ok(Vec::new());
+        };
+        writer.flush()?;
+        drop(writer);
+
+        let path = self.level_path(level);
+        let mut algos = Vec::new();
+        for line in BufReader::new(File::open(&path)?).lines() {
+            let algo = line?
+                .split_whitespace()
+                .map(|index| index.parse::<usize>())
+                .collect::<Result<Vec<usize>, _>>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            algos.push(algo);
+        }
+        fs::remove_file(&path)?;
+        Ok(algos)
+    }
+}
+
+impl Drop for LevelBuckets {
+    fn drop(&mut self) {
+        self.writers.clear();
+        let _ = fs::remove_dir_all(&self.folder);
+    }
+}
+
+/// Streams the global algorithms into activation-level buckets under `folder`.
+///
+/// With `max_levels > 0` only the `max_levels` lowest activation levels seen so far
+/// are kept, so algorithms of levels ProgressiveValidationByLevels will never reach
+/// are dropped as soon as they are produced instead of being held until the end.
+pub fn combine_streaming_by_activation(
+    list_of_executions: &[Vec<Vec<usize>>],
+    list_of_parallel_rules: &[ParallelRules],
+    streaming: StreamingCombination,
+    max_levels: usize,
+    folder: &Path,
+) -> LevelBuckets {
+    let stage = start_stage("combination");
+    let mut buckets = LevelBuckets::create(folder).unwrap_or_else(|e| {
+        panic!("❌ Failed to create {}: {}", folder.display(), e);
+    });
+    let mut produced = 0;
+
+    for algo in stream_combinations(list_of_executions, list_of_parallel_rules, streaming) {
+        produced += 1;
+        let activation = calculate_total_activation(&algo, list_of_parallel_rules);
+        let levels = buckets.levels();
+        if max_levels > 0 && levels.len() >= max_levels && !levels.contains(&activation) {
+            match levels.last() {
+                Some(&highest) if activation < highest => buckets.discard(highest),
+                _ => continue,
+            }
+        }
+        if let Err(e) = buckets.push(activation, &algo) {
+            panic!("❌ Failed to write to {}: {}", folder.display(), e);
+        }
+    }
+
+    if produced == 0 {
+        panic!("❌ No valid combination of the goals!\n💡 Edit the goals and try again");
+    }
+
+    let note = format!(
+        "Streaming combination in {}: {} global algos produced, {} kept",
+        format_elapsed_time(stage.start),
+        produced,
+        buckets.len()
+    );
+    stage.finish_with_note(
+        &[("produced", produced), ("global_algos", buckets.len())],
+        &note,
+    );
+    buckets
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use itertools::Itertools;
 
 use crate::{
@@ -7,7 +9,7 @@ use crate::{
     },
     modules::{
         color::get_colors,
-        direction::{self, rotate_direction},
+        direction::{self, rotate_direction, Direction},
         progress_helper::ProgressHelper,
         rule,
         view::{self, are_equivalent, rotate_view, View},
@@ -16,8 +18,17 @@ use crate::{
 
 use rayon::prelude::*;
 
+/// A rule reduced to the smallest of its four rotations: (sorted view, direction, color)
+pub type CanonicalRule = (View, Direction, char);
+
+/// Removes algorithms that are a color permutation (plus rotations) of an earlier one.
+///
+/// Every algorithm gets a canonical form once: the smallest sorted list of canonical rules
+/// over all color permutations. Pairwise-equivalent algorithms share a canonical form, so
+/// `are_algorithms_equivalent_with_color_permutation` only runs within a bucket of equal
+/// forms, where it verifies the match exactly as before.
 pub fn remove_duplicates_by_color_switches(
-    mut algos: Vec<Vec<usize>>,
+    algos: Vec<Vec<usize>>,
     original_algo: &[usize],
 ) -> Vec<Vec<usize>> {
     let colors: Vec<char> = get_colors(&CONFIG.all_color_letters.to_vec(), CONFIG.number_of_colors);
@@ -33,50 +44,100 @@ pub fn remove_duplicates_by_color_switches(
         "Cleaning duplicate algorithms by color switches ",
     );
 
-    let mut i = 0;
-    while i < algos.len() {
-        let algo_a = algos[i].clone();
-        let full_a: Vec<usize> = original_algo.iter().chain(algo_a.iter()).cloned().collect();
-
-        algos = algos
-            .into_par_iter()
-            .enumerate()
-            .filter_map(|(j, algo_b)| {
-                if j <= i {
-                    return Some(algo_b);
-                }
+    let canonical_forms: Vec<Vec<CanonicalRule>> = {
+        let rules = get_rules();
+        let views = get_views();
+        algos
+            .par_iter()
+            .map(|algo| {
+                let full: Vec<usize> = original_algo.iter().chain(algo.iter()).cloned().collect();
+                canonical_algorithm(&full, &rules, &views, &colors)
+            })
+            .collect()
+    };
 
-                let full_b: Vec<usize> =
-                    original_algo.iter().chain(algo_b.iter()).cloned().collect();
-
-                // Parallel check for duplicates across permutations
-                let is_duplicate = possible_permutations.par_iter().any(|perm| {
-                    are_algorithms_equivalent_with_color_permutation(
-                        &full_a,
-                        &full_b,
-                        perm,
-                        &colors,
-                        &original_algo,
-                    )
-                });
-
-                if is_duplicate {
-                    None
-                } else {
-                    Some(algo_b)
-                }
+    let mut unique_algos: Vec<Vec<usize>> = Vec::new();
+    let mut buckets: HashMap<Vec<CanonicalRule>, Vec<usize>> = HashMap::new();
+
+    for (algo_b, canonical_form) in algos.into_iter().zip(canonical_forms) {
+        let full_b: Vec<usize> = original_algo.iter().chain(algo_b.iter()).cloned().collect();
+        let bucket = buckets.entry(canonical_form).or_default();
+
+        let is_duplicate = bucket.iter().any(|&i| {
+            let full_a: Vec<usize> = original_algo
+                .iter()
+                .chain(unique_algos[i].iter())
+                .cloned()
+                .collect();
+            possible_permutations.par_iter().any(|perm| {
+                are_algorithms_equivalent_with_color_permutation(
+                    &full_a,
+                    &full_b,
+                    perm,
+                    &colors,
+                    original_algo,
+                )
             })
-            .collect();
+        });
 
+        if !is_duplicate {
+            bucket.push(unique_algos.len());
+            unique_algos.push(algo_b);
+        }
         progress.inc();
-        i += 1;
     }
 
     progress.finish_success(
         "Cleaning duplicate algorithms by color switches",
-        algos.len(),
+        unique_algos.len(),
     );
-    algos
+    unique_algos
+}
+
+/// Canonical form of an algorithm under color permutation and rule rotation.
+/// Assumes the rules of an algorithm are pairwise distinct up to rotation, which holds
+/// after `convert_and_deduplicate_rules_in_each_algorithm`.
+fn canonical_algorithm(
+    algo: &[usize],
+    rules: &[rule::Rule],
+    views: &[View],
+    colors: &[char],
+) -> Vec<CanonicalRule> {
+    colors
+        .iter()
+        .permutations(colors.len())
+        .map(|perm| {
+            let perm: Vec<char> = perm.into_iter().cloned().collect();
+            let mut canonical_rules: Vec<CanonicalRule> = algo
+                .iter()
+                .map(|&rule_id| {
+                    let rule = &rules[rule_id];
+                    let view = apply_color_permutation_to_view(
+                        views[rule.view_id].clone(),
+                        &perm,
+                        colors,
+                    );
+                    let color = apply_color_permutation_to_color(rule.color, &perm, colors);
+                    canonical_rule(&view, rule.direction, color)
+                })
+                .collect();
+            canonical_rules.sort_unstable();
+            canonical_rules
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+pub fn canonical_rule(view: &View, direction: Direction, color: char) -> CanonicalRule {
+    [0, 90, 180, 270]
+        .iter()
+        .map(|&angle| {
+            let mut rotated = rotate_view(view, angle);
+            rotated.sort_unstable();
+            (rotated, rotate_direction(&direction, angle), color)
+        })
+        .min()
+        .unwrap()
 }
 
 pub fn remove_duplicates_by_color_switches_(
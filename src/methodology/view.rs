@@ -7,31 +7,31 @@ use crate::{
 };
 use std::process::exit;
 
-use super::globals::{get_number_of_robots, get_visibility};
-
-pub fn generate_views(colors: &Vec<char>) -> Vec<View> {
-    let mut views = generate_robot_view(
-        vec![vec![('r', 0, 0)]],
-        *get_number_of_robots() - 1,
-        *get_visibility(),
-    );
+/// Every view of `number_of_robots` robots within `visibility`, up to rotation
+pub fn generate_views(
+    colors: &Vec<char>,
+    number_of_robots: usize,
+    visibility: i16,
+    opacity: bool,
+) -> Vec<View> {
+    let mut views = generate_robot_view(vec![vec![('r', 0, 0)]], number_of_robots - 1, visibility);
     remove_identical_views(&mut views);
     distribute_robot_colors_iterative(&mut views, &colors);
-    views = distribute_obstacles(&views);
+    views = distribute_obstacles(&views, visibility);
     //  apply_abstract_positions_distribution(&mut views);
-    remove_symmetrical_rotations(&mut views, CONFIG.opacity, CONFIG.visibility_range);
+    remove_symmetrical_rotations(&mut views, opacity, visibility);
     remove_identical_views(&mut views);
     views
 }
 
-pub fn distribute_obstacles(views: &Vec<View>) -> Vec<View> {
+pub fn distribute_obstacles(views: &Vec<View>, visibility: i16) -> Vec<View> {
     let mut views_with_obstacles: Vec<View> = Vec::new();
 
     for view in views {
         views_with_obstacles.push(view.clone());
-        for j in (-*get_visibility()..=*get_visibility()).rev() {
-            for i in -*get_visibility()..=*get_visibility() {
-                if j.abs() + i.abs() <= *get_visibility() {
+        for j in (-visibility..=visibility).rev() {
+            for i in -visibility..=visibility {
+                if j.abs() + i.abs() <= visibility {
                     // Only add an obstacle if (i, j) is NOT already in view
                     if !exists_in_view(&i, &j, view) {
                         let mut view_copy = view.clone();
@@ -19,213 +19,6 @@ pub struct AlgorithmExperiments {
 }
 
 impl AlgorithmExperiments {
-    pub fn hash_by_rules_count(&self) -> String {
-        let total: usize = self
-            .infos
-            .by_robot_colors
-            .iter()
-            .map(|info| info.rules_count)
-            .sum();
-        total.to_string()
-    }
-    pub fn hash_by_idle_rules_count(&self) -> String {
-        let total: usize = self
-            .infos
-            .by_robot_colors
-            .iter()
-            .map(|info| info.idle_rules_count)
-            .sum();
-        total.to_string()
-    }
-
-    pub fn hash_by_opac_rules_count(&self) -> String {
-        let total: usize = self
-            .infos
-            .by_robot_colors
-            .iter()
-            .map(|info| info.opacity_rule_count)
-            .sum();
-        total.to_string()
-    }
-    // ...existing code...
-
-    pub fn hash_family_by_rules_count_by_colors(&self) -> String {
-        self.infos
-            .by_robot_colors
-            .iter()
-            .map(|info| format!("{}:{}", info.robot_color, info.rules_count))
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_family_by_idle_rules_count_by_colors(&self) -> String {
-        self.infos
-            .by_robot_colors
-            .iter()
-            .map(|info| format!("{}:{}", info.robot_color, info.idle_rules_count))
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_family_by_opacity_rules_count_by_colors(&self) -> String {
-        self.infos
-            .by_robot_colors
-            .iter()
-            .map(|info| format!("{}:{}", info.robot_color, info.opacity_rule_count))
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    // ...existing code...
-
-    pub fn hash_by_rules_count_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Calculate total rules used in this experiment
-                let total_rules: usize = exp
-                    .robots_metrics
-                    .iter()
-                    .map(|robot| robot.rule_count)
-                    .sum();
-                // format!("{:04}", total_rules)
-                total_rules.to_string()
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-    pub fn hash_by_total_activation_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Calculate total rules used in this experiment
-                let total_rules: usize = exp
-                    .robots_metrics
-                    .iter()
-                    .map(|robot| robot.total_activation)
-                    .sum();
-                // format!("{:04}", total_rules)
-                total_rules.to_string()
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-    pub fn hash_by_color_activation_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Calculate total rules used in this experiment
-                let total_rules: usize = exp
-                    .robots_metrics
-                    .iter()
-                    .map(|robot| robot.color_activations)
-                    .sum();
-                // format!("{:04}", total_rules)
-                total_rules.to_string()
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_movement_activation_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Calculate total rules used in this experiment
-                let total_rules: usize = exp
-                    .robots_metrics
-                    .iter()
-                    .map(|robot| robot.movement_activations)
-                    .sum();
-                // format!("{:04}", total_rules)
-                total_rules.to_string()
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_rules_count_in_executions_by_robot(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Calculate total rules used in this experiment
-                exp.robots_metrics
-                    .iter()
-                    .map(|robot| format!("{}:{}", robot.robot_id, robot.rule_count))
-                    .collect::<Vec<_>>()
-                    .join(",")
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-    pub fn hash_by_activation_in_executions_by_robot(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Create string like "r:14,g:20,b:15" for each experiment
-                exp.robots_metrics
-                    .iter()
-                    .map(|robot| format!("{}:{}", robot.robot_id, robot.total_activation))
-                    .collect::<Vec<_>>()
-                    .join(",")
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_color_activation_in_executions_by_robot(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Create string like "r:14,g:20,b:15" for each experiment
-                exp.robots_metrics
-                    .iter()
-                    .map(|robot| format!("{}:{}", robot.robot_id, robot.color_activations))
-                    .collect::<Vec<_>>()
-                    .join(",")
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-    pub fn hash_by_movement_activation_in_executions_by_robot(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                // Create string like "r:14,g:20,b:15" for each experiment
-                exp.robots_metrics
-                    .iter()
-                    .map(|robot| format!("{}:{}", robot.robot_id, robot.movement_activations))
-                    .collect::<Vec<_>>()
-                    .join(",")
-            })
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_steps_taken_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| exp.steps_taken.to_string())
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_cycle_len_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| exp.cycle_len.to_string())
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
-    pub fn hash_by_total_activation_in_cycle_in_executions(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| exp.total_activation_in_cycle.to_string())
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-
     pub fn hash_by_cycle_paths_in_executions(&self) -> String {
         self.experiments
             .iter()
@@ -298,24 +91,6 @@ impl AlgorithmExperiments {
             .join("-")
     }
 
-    pub fn hash_by_activation_in_executions_before_cycle(&self) -> String {
-        self.experiments
-            .iter()
-            .map(|exp| {
-                //do th sum oc total activation of robots
-                let total_activation: usize = exp
-                    .robots_metrics
-                    .iter()
-                    .map(|robot| robot.total_activation)
-                    .sum();
-                let total_activation_before_cycle =
-                    total_activation - exp.total_activation_in_cycle;
-                format!("{}", total_activation)
-            })
-            .collect::<Vec<_>>()
-            .join(",")
-    }
-
     pub fn hash_by_color_in_executions(&self) -> String {
         self.experiments
             .iter()
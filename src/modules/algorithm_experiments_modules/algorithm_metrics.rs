@@ -12,6 +12,7 @@ pub struct AlgorithmMetrics {
     pub blocked: usize,          // Blocked configurations
     pub cyclic: usize,           // Cyclic configurations
     pub timeout: usize,          // Timed out configurations
+    pub skipped: usize,          // Configurations skipped by the validation policy
 }
 
 impl AlgorithmMetrics {
@@ -23,6 +24,7 @@ impl AlgorithmMetrics {
             blocked: count_status(config_outcomes, ExecutionStatus::Blocked),
             cyclic: count_status(config_outcomes, ExecutionStatus::Cycle),
             timeout: count_status(config_outcomes, ExecutionStatus::Timeout),
+            skipped: count_status(config_outcomes, ExecutionStatus::Skipped),
         }
     }
 }
@@ -16,4 +16,10 @@ pub struct ExperimentResult {
     pub total_activation_in_cycle: usize,
     pub total_activation: usize,
     pub robots_metrics: Vec<RobotCriteria>,
+    /// Times each final rule fired, by rule index, summed over robots
+    #[serde(default)]
+    pub rule_fires: Vec<usize>,
+    /// Same as `rule_fires`, restricted to the final cycle
+    #[serde(default)]
+    pub rule_fires_in_cycle: Vec<usize>,
 }
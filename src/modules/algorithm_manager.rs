@@ -1,9 +1,11 @@
 use serde_json;
 use std::error::Error;
 use std::fs;
+use std::path::Path;
 
 use crate::modules::full_rule::print_full_rule;
 use crate::modules::rule::print_rule;
+use crate::validation::algorithm_loader::{load_algorithm, AlgorithmFormat};
 
 use super::algorithm::Algorithm;
 use super::position::Position;
@@ -24,11 +26,18 @@ impl AlgorithmManager {
     pub fn add_algorithm(&mut self, algorithm: Algorithm) {
         self.algorithms.push(algorithm);
     }
-    // Constructor: Loads algorithms from JSON file
+    // Constructor: Loads algorithms from a JSON file, or a single one from a .web-algo/YAML file
     pub fn new(path: &str) -> Result<Self, Box<dyn Error>> {
         let filename = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), path);
-        let data = fs::read_to_string(filename)?; // Optimized file reading
-        let algorithms: Vec<Algorithm> = serde_json::from_str(&data)?;
+        let algorithms: Vec<Algorithm> = match AlgorithmFormat::from_path(Path::new(&filename)) {
+            Some(AlgorithmFormat::WebAlgo) | Some(AlgorithmFormat::Yaml) => {
+                vec![load_algorithm(Path::new(&filename))?]
+            }
+            _ => {
+                let data = fs::read_to_string(filename)?; // Optimized file reading
+                serde_json::from_str(&data)?
+            }
+        };
 
         println!("Algorithms loaded successfully.");
         Ok(Self { algorithms })
@@ -5,4 +5,5 @@ pub struct AlgorithmSnapshot {
     pub blocked: Vec<String>,
     pub cyclic: Vec<String>,
     pub timeout: Vec<String>,
+    pub unreadable: Vec<String>,
 }
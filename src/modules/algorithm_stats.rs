@@ -15,6 +15,7 @@ pub struct AlgorithmStats {
     blocked: Arc<Mutex<HashSet<String>>>,
     cyclic: Arc<Mutex<HashSet<String>>>,
     timeout: Arc<Mutex<HashSet<String>>>,
+    unreadable: Arc<Mutex<HashSet<String>>>,
 }
 
 impl AlgorithmStats {
@@ -25,6 +26,7 @@ impl AlgorithmStats {
             AlgorithmStatus::Blocked => &self.blocked,
             AlgorithmStatus::Cyclic => &self.cyclic,
             AlgorithmStatus::Timeout => &self.timeout,
+            AlgorithmStatus::Unreadable => &self.unreadable,
             AlgorithmStatus::Unknown => return,
         };
         if let Ok(mut set) = target.lock() {
@@ -39,6 +41,7 @@ impl AlgorithmStats {
             blocked: Self::collect_sorted(&self.blocked),
             cyclic: Self::collect_sorted(&self.cyclic),
             timeout: Self::collect_sorted(&self.timeout),
+            unreadable: Self::collect_sorted(&self.unreadable),
         }
     }
     fn collect_sorted(set: &Arc<Mutex<HashSet<String>>>) -> Vec<String> {
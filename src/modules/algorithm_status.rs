@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
 pub enum AlgorithmStatus {
     Validated,
     ValidatedNotLd,
     Blocked,
     Cyclic,
     Timeout,
+    /// The file could not be parsed, so it was not simulated
+    Unreadable,
+    #[default]
     Unknown,
 }
 
@@ -18,6 +21,7 @@ impl AlgorithmStatus {
             AlgorithmStatus::Blocked => "[BLOCKED]",
             AlgorithmStatus::Cyclic => "[CYCLIC]",
             AlgorithmStatus::Timeout => "[TIMEOUT]",
+            AlgorithmStatus::Unreadable => "[UNREADABLE]",
             AlgorithmStatus::Unknown => "[UNKNOWN]",
         }
     }
@@ -28,6 +32,7 @@ impl AlgorithmStatus {
             AlgorithmStatus::Blocked => "blocked",
             AlgorithmStatus::Cyclic => "cyclic",
             AlgorithmStatus::Timeout => "timeout",
+            AlgorithmStatus::Unreadable => "unreadable",
             AlgorithmStatus::Unknown => "unknown",
         }
     }
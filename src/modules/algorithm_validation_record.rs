@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{algorithm_status::AlgorithmStatus, execution_status::ExecutionStatus};
+
+/// Outcome of validating one algorithm file, kept until the summary log is written.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgorithmValidationRecord {
+    /// Position of the file in the sorted list returned by `get_algo_files`
+    pub index: usize,
+    pub file_name: String,
+    pub status: AlgorithmStatus,
+    pub config_outcomes: Vec<ExecutionStatus>,
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockedConfigSummary {
     pub algorithm_name: String,
     pub blocked_config_index: usize,
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::algorithm_status::AlgorithmStatus;
+
+/// The parts of config.json an algorithm was generated and validated under
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogConfig {
+    pub number_of_robots: usize,
+    pub number_of_colors: usize,
+    pub visibility_range: i16,
+    pub all_color_letters: Vec<char>,
+    pub is_obstacle_opaque: bool,
+    /// Hash of the whole config, to tell apart configs that share the fields above
+    pub fingerprint: String,
+}
+
+/// A classification family group the algorithm fell into
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogFamily {
+    pub family_number: usize,
+    pub title: String,
+    /// `FamilyGroup::hash`
+    pub group: String,
+}
+
+/// One validation of an algorithm of the entry's class
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogOccurrence {
+    /// Folder that was validated
+    pub execution: String,
+    pub file_name: String,
+    pub status: AlgorithmStatus,
+    pub recorded_at: String,
+    pub config: CatalogConfig,
+    /// Empty unless the algorithm was classified (validated LD only)
+    pub families: Vec<CatalogFamily>,
+}
+
+/// An algorithm up to rotation and color permutation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    /// `canonical_hash` of its rules
+    pub hash: String,
+    pub rule_count: usize,
+    pub visibility: i16,
+    /// Copy of the first file seen, in the catalog `algorithms/` folder
+    pub file: String,
+    pub first_seen: String,
+    pub occurrences: Vec<CatalogOccurrence>,
+}
+
+/// `results/catalog/index.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Catalog {
+    pub entries: BTreeMap<String, CatalogEntry>,
+}
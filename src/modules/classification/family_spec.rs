@@ -0,0 +1,496 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::algorithm_experiments_modules::algorithm_experiments::AlgorithmExperiments;
+
+/// What a declared family measures. Rule metrics come from the algorithm itself
+/// (one value per robot color); the others from its validation experiments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FamilyMetric {
+    // --- Rules of the algorithm, per robot color ---
+    RulesCount,
+    IdleRulesCount,
+    OpacityRulesCount,
+    // --- Per robot, per experiment ---
+    RulesUsed,
+    IdleRulesUsed,
+    Activation,
+    ColorActivations,
+    MovementActivations,
+    // --- Per experiment ---
+    StepsTaken,
+    CycleLen,
+    ActivationInCycle,
+    /// Activation of all robots before the cycle
+    ActivationBeforeCycle,
+}
+
+/// How the values of a metric become the family signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FamilyAggregation {
+    /// One total
+    Sum,
+    /// Largest experiment total (largest color value for rule metrics)
+    Max,
+    /// Experiment totals in experiment order
+    PerExperiment,
+    /// Total per robot id in each experiment, in experiment order
+    PerExperimentPerRobot,
+    /// Total per robot id over all experiments
+    PerRobot,
+    /// Total per robot color (the robot's initial color for experiment metrics)
+    PerColor,
+}
+
+/// Applied to every value before it enters the signature
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FamilyBucketing {
+    #[default]
+    Exact,
+    /// Buckets `[k·width, (k+1)·width)`
+    Width(usize),
+    /// Sorted bounds: `<t0`, `t0-t1`, ..., `>=tn`
+    Thresholds(Vec<usize>),
+}
+
+/// A classification family: the numeric built-in families, then the ones declared in
+/// `classification_families` of config.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassificationFamilySpec {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub metric: FamilyMetric,
+    pub aggregation: FamilyAggregation,
+    #[serde(default)]
+    pub bucketing: FamilyBucketing,
+}
+
+/// One value of a metric with what it belongs to
+struct Sample {
+    experiment: Option<usize>,
+    robot: Option<usize>,
+    color: Option<char>,
+    value: usize,
+}
+
+impl ClassificationFamilySpec {
+    /// A family with exact values
+    pub fn exact(
+        title: &str,
+        description: &str,
+        metric: FamilyMetric,
+        aggregation: FamilyAggregation,
+    ) -> Self {
+        ClassificationFamilySpec {
+            title: title.to_string(),
+            description: description.to_string(),
+            metric,
+            aggregation,
+            bucketing: FamilyBucketing::Exact,
+        }
+    }
+
+    /// Rejects aggregations the metric has no data for
+    pub fn validate(&self) -> Result<(), String> {
+        use FamilyAggregation::*;
+        use FamilyMetric::*;
+        let invalid = match self.metric {
+            RulesCount | IdleRulesCount | OpacityRulesCount => {
+                matches!(
+                    self.aggregation,
+                    PerExperiment | PerExperimentPerRobot | PerRobot
+                )
+            }
+            StepsTaken | CycleLen | ActivationInCycle | ActivationBeforeCycle => {
+                matches!(
+                    self.aggregation,
+                    PerExperimentPerRobot | PerRobot | PerColor
+                )
+            }
+            _ => false,
+        };
+        if invalid {
+            return Err(format!(
+                "family '{}': {:?} cannot be aggregated {:?}",
+                self.title, self.metric, self.aggregation
+            ));
+        }
+        match &self.bucketing {
+            FamilyBucketing::Width(0) => {
+                return Err(format!(
+                    "family '{}': bucket width must be positive",
+                    self.title
+                ));
+            }
+            FamilyBucketing::Thresholds(bounds)
+                if bounds.windows(2).any(|pair| pair[0] >= pair[1]) =>
+            {
+                return Err(format!(
+                    "family '{}': thresholds must be strictly increasing",
+                    self.title
+                ));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn signature(&self, algo: &AlgorithmExperiments) -> String {
+        let samples = self.samples(algo);
+        match self.aggregation {
+            FamilyAggregation::Sum => self.bucket(samples.iter().map(|s| s.value).sum()),
+            FamilyAggregation::Max => {
+                let totals = group_totals(samples.iter(), |sample| {
+                    sample
+                        .experiment
+                        .map(|experiment| experiment.to_string())
+                        .or(sample.color.map(String::from))
+                });
+                self.bucket(totals.values().copied().max().unwrap_or(0))
+            }
+            FamilyAggregation::PerExperiment => {
+                let mut totals = vec![0; algo.experiments.len()];
+                for sample in &samples {
+                    if let Some(experiment) = sample.experiment {
+                        totals[experiment] += sample.value;
+                    }
+                }
+                totals
+                    .into_iter()
+                    .map(|total| self.bucket(total))
+                    .collect::<Vec<_>>()
+                    .join("-")
+            }
+            FamilyAggregation::PerExperimentPerRobot => (0..algo.experiments.len())
+                .map(|experiment| {
+                    let in_experiment: Vec<&Sample> = samples
+                        .iter()
+                        .filter(|sample| sample.experiment == Some(experiment))
+                        .collect();
+                    let totals = group_totals(in_experiment.into_iter(), |sample| {
+                        sample.robot.map(|robot| robot.to_string())
+                    });
+                    self.keyed(totals, ",")
+                })
+                .collect::<Vec<_>>()
+                .join("-"),
+            FamilyAggregation::PerRobot => self.keyed(
+                group_totals(samples.iter(), |sample| {
+                    sample.robot.map(|robot| robot.to_string())
+                }),
+                "-",
+            ),
+            FamilyAggregation::PerColor => self.keyed(
+                group_totals(samples.iter(), |sample| sample.color.map(String::from)),
+                "-",
+            ),
+        }
+    }
+
+    fn samples(&self, algo: &AlgorithmExperiments) -> Vec<Sample> {
+        use FamilyMetric::*;
+        match self.metric {
+            RulesCount | IdleRulesCount | OpacityRulesCount => algo
+                .infos
+                .by_robot_colors
+                .iter()
+                .map(|info| Sample {
+                    experiment: None,
+                    robot: None,
+                    color: Some(info.robot_color),
+                    value: match self.metric {
+                        RulesCount => info.rules_count,
+                        IdleRulesCount => info.idle_rules_count,
+                        _ => info.opacity_rule_count,
+                    },
+                })
+                .collect(),
+            RulesUsed | IdleRulesUsed | Activation | ColorActivations | MovementActivations => algo
+                .experiments
+                .iter()
+                .enumerate()
+                .flat_map(|(experiment, exp)| {
+                    exp.robots_metrics.iter().map(move |robot| Sample {
+                        experiment: Some(experiment),
+                        robot: Some(robot.robot_id),
+                        color: Some(robot.robot_position.0),
+                        value: match self.metric {
+                            RulesUsed => robot.rule_count,
+                            IdleRulesUsed => robot.idle_rule_count,
+                            Activation => robot.total_activation,
+                            ColorActivations => robot.color_activations,
+                            _ => robot.movement_activations,
+                        },
+                    })
+                })
+                .collect(),
+            StepsTaken | CycleLen | ActivationInCycle | ActivationBeforeCycle => algo
+                .experiments
+                .iter()
+                .enumerate()
+                .map(|(experiment, exp)| Sample {
+                    experiment: Some(experiment),
+                    robot: None,
+                    color: None,
+                    value: match self.metric {
+                        StepsTaken => exp.steps_taken,
+                        CycleLen => exp.cycle_len,
+                        ActivationInCycle => exp.total_activation_in_cycle,
+                        _ => exp
+                            .robots_metrics
+                            .iter()
+                            .map(|robot| robot.total_activation)
+                            .sum::<usize>()
+                            .saturating_sub(exp.total_activation_in_cycle),
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    fn keyed(&self, totals: BTreeMap<String, usize>, separator: &str) -> String {
+        totals
+            .into_iter()
+            .map(|(key, total)| format!("{}:{}", key, self.bucket(total)))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn bucket(&self, value: usize) -> String {
+        match &self.bucketing {
+            FamilyBucketing::Exact => value.to_string(),
+            FamilyBucketing::Width(width) => {
+                let low = value / width * width;
+                format!("[{},{})", low, low + width)
+            }
+            FamilyBucketing::Thresholds(bounds) => {
+                match bounds.iter().position(|&bound| value < bound) {
+                    Some(0) => format!("<{}", bounds[0]),
+                    Some(index) => format!("{}-{}", bounds[index - 1], bounds[index] - 1),
+                    None => match bounds.last() {
+                        Some(last) => format!(">={}", last),
+                        None => value.to_string(),
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Sums of the samples sharing a key; samples without a key are left out
+fn group_totals<'a>(
+    samples: impl Iterator<Item = &'a Sample>,
+    key: impl Fn(&Sample) -> Option<String>,
+) -> BTreeMap<String, usize> {
+    let mut totals = BTreeMap::new();
+    for sample in samples {
+        if let Some(key) = key(sample) {
+            *totals.entry(key).or_insert(0) += sample.value;
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::{
+        algorithm_experiments_modules::{
+            algo_info_by_robot_colors::AlgoInfoByRobotColors, algo_infos::AlgoInfos,
+            experiment_result::ExperimentResult, robot_criteria::RobotCriteria,
+        },
+        algorithm_status::AlgorithmStatus,
+        execution_status::ExecutionStatus,
+        grid_config::GridConfig,
+        grid_experiment::GridExperiment,
+        init_config::InitConfig,
+    };
+
+    fn spec(
+        metric: FamilyMetric,
+        aggregation: FamilyAggregation,
+        bucketing: FamilyBucketing,
+    ) -> ClassificationFamilySpec {
+        ClassificationFamilySpec {
+            bucketing,
+            ..ClassificationFamilySpec::exact("test", "", metric, aggregation)
+        }
+    }
+
+    fn robot(
+        robot_id: usize,
+        color: char,
+        rule_count: usize,
+        total_activation: usize,
+    ) -> RobotCriteria {
+        RobotCriteria {
+            robot_id,
+            robot_position: (color, robot_id as i16, 0),
+            total_activation,
+            rule_count,
+            ..RobotCriteria::default()
+        }
+    }
+
+    fn experiment(
+        experiment_id: usize,
+        steps_taken: usize,
+        activation_in_cycle: usize,
+        robots_metrics: Vec<RobotCriteria>,
+    ) -> ExperimentResult {
+        ExperimentResult {
+            experiment_id,
+            grid_experiment: GridExperiment {
+                id: experiment_id,
+                grid_config: GridConfig {
+                    columns: 3,
+                    rows: 3,
+                    min_x: 0,
+                    max_x: 4,
+                    min_y: 0,
+                    max_y: 4,
+                    obstacle_position: (2, 2),
+                },
+                init_config: InitConfig {
+                    current_position_index: 0,
+                    initial_positions: vec![],
+                    is_essential: true,
+                },
+            },
+            status: ExecutionStatus::Validated,
+            steps_taken,
+            cycle_len: 2,
+            total_activation_in_cycle: activation_in_cycle,
+            total_activation: robots_metrics
+                .iter()
+                .map(|robot| robot.total_activation)
+                .sum(),
+            robots_metrics,
+            rule_fires: vec![],
+            rule_fires_in_cycle: vec![],
+        }
+    }
+
+    /// Two colors with 3 and 5 rules; two experiments with robots 0 (F) and 1 (L)
+    fn algorithm() -> AlgorithmExperiments {
+        let color = |robot_color, rules_count| AlgoInfoByRobotColors {
+            robot_color,
+            rules_count,
+            ..AlgoInfoByRobotColors::default()
+        };
+        AlgorithmExperiments {
+            name: "algo".to_string(),
+            status: AlgorithmStatus::Validated,
+            infos: AlgoInfos {
+                total_activation: 0,
+                by_robot_colors: vec![color('F', 3), color('L', 5)],
+            },
+            experiments: vec![
+                experiment(0, 12, 4, vec![robot(0, 'F', 2, 7), robot(1, 'L', 1, 3)]),
+                experiment(1, 20, 6, vec![robot(0, 'F', 4, 9), robot(1, 'L', 3, 11)]),
+            ],
+        }
+    }
+
+    #[test]
+    fn validate_rejects_aggregations_without_data_and_bad_buckets() {
+        use FamilyAggregation::*;
+        use FamilyMetric::*;
+        assert!(spec(RulesCount, PerColor, FamilyBucketing::Exact)
+            .validate()
+            .is_ok());
+        assert!(spec(RulesCount, PerExperiment, FamilyBucketing::Exact)
+            .validate()
+            .is_err());
+        assert!(
+            spec(RulesCount, PerExperimentPerRobot, FamilyBucketing::Exact)
+                .validate()
+                .is_err()
+        );
+        assert!(spec(StepsTaken, PerRobot, FamilyBucketing::Exact)
+            .validate()
+            .is_err());
+        assert!(spec(CycleLen, PerExperiment, FamilyBucketing::Exact)
+            .validate()
+            .is_ok());
+        assert!(spec(Activation, PerColor, FamilyBucketing::Exact)
+            .validate()
+            .is_ok());
+
+        assert!(spec(StepsTaken, Sum, FamilyBucketing::Width(0))
+            .validate()
+            .is_err());
+        assert!(spec(StepsTaken, Sum, FamilyBucketing::Width(1))
+            .validate()
+            .is_ok());
+        assert!(
+            spec(StepsTaken, Sum, FamilyBucketing::Thresholds(vec![5, 5]))
+                .validate()
+                .is_err()
+        );
+        assert!(
+            spec(StepsTaken, Sum, FamilyBucketing::Thresholds(vec![10, 5]))
+                .validate()
+                .is_err()
+        );
+        assert!(spec(StepsTaken, Sum, FamilyBucketing::Thresholds(vec![]))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn bucket_edges() {
+        let width = spec(
+            FamilyMetric::StepsTaken,
+            FamilyAggregation::Sum,
+            FamilyBucketing::Width(10),
+        );
+        assert_eq!(width.bucket(0), "[0,10)");
+        assert_eq!(width.bucket(9), "[0,10)");
+        assert_eq!(width.bucket(10), "[10,20)");
+
+        let thresholds = spec(
+            FamilyMetric::StepsTaken,
+            FamilyAggregation::Sum,
+            FamilyBucketing::Thresholds(vec![10, 20]),
+        );
+        assert_eq!(thresholds.bucket(9), "<10");
+        assert_eq!(thresholds.bucket(10), "10-19");
+        assert_eq!(thresholds.bucket(19), "10-19");
+        assert_eq!(thresholds.bucket(20), ">=20");
+
+        let no_bounds = spec(
+            FamilyMetric::StepsTaken,
+            FamilyAggregation::Sum,
+            FamilyBucketing::Thresholds(vec![]),
+        );
+        assert_eq!(no_bounds.bucket(7), "7");
+    }
+
+    #[test]
+    fn signature_per_aggregation() {
+        use FamilyAggregation::*;
+        use FamilyMetric::*;
+        let algo = algorithm();
+        let exact = |metric, aggregation| {
+            spec(metric, aggregation, FamilyBucketing::Exact).signature(&algo)
+        };
+
+        assert_eq!(exact(RulesCount, Sum), "8");
+        assert_eq!(exact(RulesCount, Max), "5");
+        assert_eq!(exact(RulesCount, PerColor), "F:3-L:5");
+        assert_eq!(exact(StepsTaken, PerExperiment), "12-20");
+        assert_eq!(exact(StepsTaken, Max), "20");
+        assert_eq!(exact(RulesUsed, PerExperiment), "3-7");
+        assert_eq!(exact(RulesUsed, PerExperimentPerRobot), "0:2,1:1-0:4,1:3");
+        assert_eq!(exact(RulesUsed, PerRobot), "0:6-1:4");
+        assert_eq!(exact(Activation, PerColor), "F:16-L:14");
+        assert_eq!(exact(Activation, Max), "20");
+        assert_eq!(exact(ActivationBeforeCycle, PerExperiment), "6-14");
+
+        let bucketed = spec(StepsTaken, PerExperiment, FamilyBucketing::Width(10));
+        assert_eq!(bucketed.signature(&algo), "[10,20)-[20,30)");
+    }
+}
@@ -3,4 +3,5 @@ pub mod algorithm_info;
 pub mod classification_result;
 pub mod family_category;
 pub mod family_group;
+pub mod family_spec;
 pub mod family_summary;
@@ -1,10 +1,13 @@
 use crate::modules::{
+    classification::family_spec::ClassificationFamilySpec,
     combination_mode::CombinationMode,
     generation_mode::GenerationMode,
     grid_config::GridConfig,
     init_config::InitConfig,
     simulation_config::{SimulationConfig, Target},
+    streaming_combination::StreamingCombination,
     validation_config::ValidationConfig,
+    validation_policy::ValidationPolicy,
 };
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +26,10 @@ pub struct Config {
     pub generation_mode: GenerationMode,
     pub opacity: bool,
     pub is_obstacle_opaque: bool,
+    #[serde(default)]
+    pub validation_policy: ValidationPolicy,
+    #[serde(default)]
+    pub streaming_combination: Option<StreamingCombination>,
 
     // --- Obstacle and Visibility Settings ---
 
@@ -36,6 +43,11 @@ pub struct Config {
     pub moving_on_space_pattern: Vec<Vec<(char, i16, i16)>>,
     pub goals: Vec<SimulationConfig>,
     pub initial_configurations: Vec<(Vec<(char, i16, i16)>, bool)>,
+
+    // --- Classification ---
+    /// Families exported after the built-in ones by `export_classification`
+    #[serde(default)]
+    pub classification_families: Vec<ClassificationFamilySpec>,
 }
 
 //cretafn display_config(config: &Config) {
@@ -64,6 +76,8 @@ impl Config {
         println!("   Generation Mode: {:?}", self.generation_mode);
         println!("   Opacity: {}", self.opacity);
         println!("   Is Obstacle Opaque: {}", self.is_obstacle_opaque);
+        println!("   Validation Policy: {:?}", self.validation_policy);
+        println!("   Streaming Combination: {:?}", self.streaming_combination);
 
         println!("\n🌐 WEB ALGORITHM CONFIGURATION");
         println!("   Web Algo Colors: {}", self.web_algo_colors);
@@ -94,6 +108,18 @@ impl Config {
             println!("     Config {}: {:?} -> {}", i + 1, config, flag);
         }
 
+        println!("\n🗂️ CLASSIFICATION");
+        println!(
+            "   Declared Families: {}",
+            self.classification_families.len()
+        );
+        for family in &self.classification_families {
+            println!(
+                "     {}: {:?} {:?} {:?}",
+                family.title, family.metric, family.aggregation, family.bucketing
+            );
+        }
+
         println!(
             "\n═══════════════════════════════════════════════════════════════════════════════"
         );
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 // Define the Direction enum (you can customize this as needed)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Direction {
     Up,
     Right,
@@ -13,14 +13,25 @@ pub enum Direction {
 impl Direction {
     pub fn from_str(s: &str) -> Self {
         match s.trim().to_lowercase().as_str() {
-            "front" => Direction::Up,
-            "back" => Direction::Down,
+            "front" | "up" => Direction::Up,
+            "back" | "down" => Direction::Down,
             "left" => Direction::Left,
             "right" => Direction::Right,
             "idle" => Direction::Idle,
             _ => Direction::Idle, // Default to Idle for unknown strings
         }
     }
+
+    /// Inverse of `from_str`: the word `.web-algo` files use for this direction
+    pub fn as_web_algo_str(&self) -> &'static str {
+        match self {
+            Direction::Up => "front",
+            Direction::Down => "back",
+            Direction::Left => "left",
+            Direction::Right => "right",
+            Direction::Idle => "idle",
+        }
+    }
 }
 pub fn calculate_movement(direction: &Direction, x: &i16, y: &i16) -> (i16, i16) {
     match direction {
@@ -64,3 +75,84 @@ pub fn rotate_direction(dir: &Direction, angle: i16) -> Direction {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::position::rotate_point;
+
+    const DIRECTIONS: [Direction; 5] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Idle,
+    ];
+    const ANGLES: [i16; 4] = [0, 90, 180, 270];
+
+    fn opposite(direction: Direction) -> Direction {
+        rotate_direction(&direction, 180)
+    }
+
+    #[test]
+    fn calculate_movement_moves_one_cell() {
+        assert_eq!(calculate_movement(&Direction::Up, &2, &3), (2, 4));
+        assert_eq!(calculate_movement(&Direction::Down, &2, &3), (2, 2));
+        assert_eq!(calculate_movement(&Direction::Left, &2, &3), (1, 3));
+        assert_eq!(calculate_movement(&Direction::Right, &2, &3), (3, 3));
+        assert_eq!(calculate_movement(&Direction::Idle, &2, &3), (2, 3));
+    }
+
+    #[test]
+    fn moving_back_returns_to_the_start() {
+        for direction in DIRECTIONS {
+            for x in -3..=3 {
+                for y in -3..=3 {
+                    let (mx, my) = calculate_movement(&direction, &x, &y);
+                    assert_eq!(calculate_movement(&opposite(direction), &mx, &my), (x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotating_four_times_is_the_identity() {
+        for direction in DIRECTIONS {
+            for angle in ANGLES {
+                let mut rotated = direction;
+                for _ in 0..4 {
+                    rotated = rotate_direction(&rotated, angle);
+                }
+                assert_eq!(rotated, direction, "angle {}", angle);
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_direction_agrees_with_rotate_point() {
+        for direction in DIRECTIONS {
+            for angle in ANGLES {
+                for x in -3..=3 {
+                    for y in -3..=3 {
+                        let (mx, my) = calculate_movement(&direction, &x, &y);
+                        let (rx, ry) = rotate_point(&x, &y, &angle);
+                        assert_eq!(
+                            calculate_movement(&rotate_direction(&direction, angle), &rx, &ry),
+                            rotate_point(&mx, &my, &angle),
+                            "{:?} rotated {}",
+                            direction,
+                            angle
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn web_algo_words_round_trip() {
+        for direction in DIRECTIONS {
+            assert_eq!(Direction::from_str(direction.as_web_algo_str()), direction);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// One line of the `<prefix>_<timestamp>.events.jsonl` file written next to the human log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionEvent {
+    /// Local time, RFC 3339
+    pub timestamp: String,
+    /// Seconds since the logger started
+    pub elapsed_secs: f64,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    ExecutionStarted {
+        /// Log file prefix: generation, validation, merge, classification
+        command: String,
+        log_file: String,
+    },
+    StageStarted {
+        stage: String,
+    },
+    StageFinished {
+        stage: String,
+        duration_secs: f64,
+        counts: BTreeMap<String, usize>,
+        memory_bytes: Option<u64>,
+    },
+    Count {
+        name: String,
+        value: usize,
+    },
+    /// Same text as the `log_note` line of the human log
+    Note {
+        text: String,
+    },
+    ExecutionFinished {
+        duration_secs: f64,
+        memory_bytes: Option<u64>,
+    },
+}
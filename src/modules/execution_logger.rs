@@ -1,16 +1,20 @@
 use chrono::Local;
 use once_cell::sync::Lazy;
-use std::fs::{create_dir_all, OpenOptions};
+use std::collections::BTreeMap;
+use std::fs::{self, create_dir_all, OpenOptions};
 use std::io::Write;
 use std::sync::Mutex;
 use std::time::{Duration, Instant, SystemTime};
 
+use crate::modules::execution_event::{EventKind, ExecutionEvent};
 pub use crate::modules::time_helper::format_elapsed_time;
 
 pub struct ExecutionLogger {
     start_time: Instant,
     system_start_time: SystemTime,
     pub file_path: String,
+    /// Typed events, one JSON object per line, next to `file_path`
+    pub events_path: String,
 }
 
 impl ExecutionLogger {
@@ -22,6 +26,7 @@ impl ExecutionLogger {
         // Timestamped file name with prefix
         let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
         let file_path = format!("{}/{}_{}.log", log_dir, filename_prefix, timestamp);
+        let events_path = format!("{}/{}_{}.events.jsonl", log_dir, filename_prefix, timestamp);
 
         let mut file = OpenOptions::new()
             .create(true)
@@ -57,11 +62,31 @@ impl ExecutionLogger {
         )
         .unwrap();
 
-        ExecutionLogger {
+        let logger = ExecutionLogger {
             start_time: Instant::now(),
             system_start_time: SystemTime::now(),
-            file_path,
-        }
+            file_path: file_path.clone(),
+            events_path,
+        };
+        logger.log_event(EventKind::ExecutionStarted {
+            command: filename_prefix.to_string(),
+            log_file: file_path,
+        });
+        logger
+    }
+
+    pub fn log_event(&self, kind: EventKind) {
+        let event = ExecutionEvent {
+            timestamp: Local::now().to_rfc3339(),
+            elapsed_secs: self.start_time.elapsed().as_secs_f64(),
+            kind,
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.events_path)
+            .unwrap();
+        writeln!(file, "{}", serde_json::to_string(&event).unwrap()).unwrap();
     }
 
     pub fn log_note<S: AsRef<str>>(&self, note: S) {
@@ -77,6 +102,9 @@ impl ExecutionLogger {
             note.as_ref()
         )
         .unwrap();
+        self.log_event(EventKind::Note {
+            text: note.as_ref().to_string(),
+        });
     }
 
     pub fn end(&self) {
@@ -120,6 +148,45 @@ impl ExecutionLogger {
             "└──────────────────────────────────────────────────────────────┘\n"
         )
         .unwrap();
+        self.log_event(EventKind::ExecutionFinished {
+            duration_secs: self.start_time.elapsed().as_secs_f64(),
+            memory_bytes: resident_memory_bytes(),
+        });
+    }
+}
+
+/// Resident set size from `/proc/self/status`; `None` where procfs is unavailable
+pub fn resident_memory_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// A timed stage of the pipeline, see `start_stage`
+pub struct StageTimer {
+    pub stage: String,
+    pub start: Instant,
+}
+
+impl StageTimer {
+    /// Logs a `StageFinished` event with the duration and `counts`
+    pub fn finish(self, counts: &[(&str, usize)]) {
+        log_event(EventKind::StageFinished {
+            stage: self.stage,
+            duration_secs: self.start.elapsed().as_secs_f64(),
+            counts: counts
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect::<BTreeMap<_, _>>(),
+            memory_bytes: resident_memory_bytes(),
+        });
+    }
+
+    /// `finish`, after writing `note` to the human log
+    pub fn finish_with_note(self, counts: &[(&str, usize)], note: &str) {
+        log_note(note);
+        self.finish(counts);
     }
 }
 
@@ -144,6 +211,30 @@ pub fn log_note(note: &str) {
     }
 }
 
+pub fn log_event(kind: EventKind) {
+    if let Some(ref logger) = *LOGGER.lock().unwrap() {
+        logger.log_event(kind);
+    }
+}
+
+/// Logs a `StageStarted` event; `finish` the returned timer to log the duration
+pub fn start_stage(stage: &str) -> StageTimer {
+    log_event(EventKind::StageStarted {
+        stage: stage.to_string(),
+    });
+    StageTimer {
+        stage: stage.to_string(),
+        start: Instant::now(),
+    }
+}
+
+pub fn log_count(name: &str, value: usize) {
+    log_event(EventKind::Count {
+        name: name.to_string(),
+        value,
+    });
+}
+
 pub fn end_logger() {
     if let Some(ref logger) = *LOGGER.lock().unwrap() {
         logger.end();
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::config::Config;
+
+/// Everything `index.html` shows about an execution folder, also written as `manifest.json`.
+/// All paths are relative to the execution root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionManifest {
+    pub execution_name: String,
+    pub generated_at: String,
+    pub config: Option<Config>,
+    #[serde(default)]
+    pub provenance: Option<Provenance>,
+    #[serde(default)]
+    pub algorithm_set: Option<AlgorithmSet>,
+    pub logs: Vec<LogTimings>,
+    pub levels: Vec<LevelSummary>,
+    pub artifacts: Vec<Artifact>,
+}
+
+/// What produced an execution, kept from the run that created the folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    pub crate_version: String,
+    /// `git rev-parse HEAD` of the working directory, if it is a git checkout
+    pub git_revision: Option<String>,
+    /// Tracked files were modified at that revision
+    pub git_dirty: bool,
+    /// `config_fingerprint` of the config used
+    pub config_hash: String,
+    /// Set in deterministic mode (`--seed`)
+    pub seed: Option<u64>,
+}
+
+/// Content hashes of the `.web-algo` files of `Algos/`, sorted by path. Two runs
+/// generated byte-identical algorithm sets iff their `hash` is equal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorithmSet {
+    pub hash: String,
+    pub files: Vec<AlgorithmFileHash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AlgorithmFileHash {
+    pub path: String,
+    pub hash: String,
+}
+
+/// Timed notes of one `ExecutionLogger` file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogTimings {
+    pub path: String,
+    pub started: Option<String>,
+    pub duration: Option<String>,
+    pub notes: Vec<TimingNote>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimingNote {
+    pub elapsed: String,
+    pub note: String,
+}
+
+/// One validated folder of `Algos/` (an activation level in progressive mode)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelSummary {
+    pub folder: String,
+    pub activation_level: Option<usize>,
+    pub algorithm_count: usize,
+    /// (label, count) pairs from the `_validation_results.log` summary
+    pub status_counts: Vec<(String, usize)>,
+    pub artifacts: Vec<Artifact>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArtifactKind {
+    Viewer,
+    Log,
+    Data,
+    Folder,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    pub path: String,
+}
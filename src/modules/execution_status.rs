@@ -13,6 +13,8 @@ pub enum ExecutionStatus {
     Cycle,
     /// Algorithm exceeded maximum allowed steps
     Timeout,
+    /// Not run because the validation policy had already decided the algorithm status
+    Skipped,
 }
 
 impl ExecutionStatus {
@@ -24,6 +26,7 @@ impl ExecutionStatus {
             ExecutionStatus::Blocked => "[BLOCKED]",
             ExecutionStatus::Cycle => "[CYCLIC]",
             ExecutionStatus::Timeout => "[TIMEOUT]",
+            ExecutionStatus::Skipped => "[SKIPPED]",
         }
     }
 
@@ -35,6 +38,7 @@ impl ExecutionStatus {
             ExecutionStatus::Blocked => "Algorithm got blocked and cannot explore",
             ExecutionStatus::Cycle => "Exploration found cycle but not fully explored",
             ExecutionStatus::Timeout => "Exploration timeout - possible infinite loop",
+            ExecutionStatus::Skipped => "Not run - status already decided by validation policy",
         }
     }
 }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    direction::Direction, execution_status::ExecutionStatus, grid_experiment::GridExperiment,
+    position::Position, view::View,
+};
+
+/// The rule a robot view matched: index in the algorithm's final rules and
+/// the rotation applied to the rule to match the view
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RuleMatch {
+    pub rule_index: usize,
+    pub rotation: i16,
+    pub direction: Direction,
+    pub color: char,
+}
+
+/// What one robot saw and did during a round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotStepTrace {
+    pub robot: usize,
+    pub position: Position,
+    pub view: View,
+    pub matched_rule: Option<RuleMatch>,
+    pub new_position: Position,
+}
+
+/// One synchronous round: the state at the start of the round and every robot's step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRound {
+    pub round: usize,
+    pub state: Vec<Position>,
+    pub robots: Vec<RobotStepTrace>,
+}
+
+/// Round-by-round replay of one algorithm on one `GridExperiment`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    pub algorithm: String,
+    pub experiment_id: usize,
+    pub grid_experiment: GridExperiment,
+    pub visibility: i16,
+    pub status: ExecutionStatus,
+    pub cycle_len: usize,
+    pub rounds: Vec<TraceRound>,
+    pub final_state: Vec<Position>,
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    methodology::configuration::CONFIG,
+    modules::{
+        algorithm_experiments_modules::experiment_result::ExperimentResult,
+        algorithm_status::AlgorithmStatus, execution_status::ExecutionStatus,
+    },
+};
+
+/// One algorithm × experiment line of `results.jsonl` / `results.csv`.
+/// Flat on purpose so notebooks can load it without reshaping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentRow {
+    pub algorithm_index: usize,
+    pub algorithm: String,
+    pub algorithm_status: AlgorithmStatus,
+    pub rule_count: usize,
+    pub visibility: i16,
+    pub experiment_id: usize,
+    pub status: ExecutionStatus,
+    pub is_essential: bool,
+    pub steps_taken: usize,
+    pub cycle_len: usize,
+    pub total_activation: usize,
+    pub total_activation_in_cycle: usize,
+    /// Summed over robots
+    pub color_activations: usize,
+    /// Summed over robots
+    pub movement_activations: usize,
+    pub columns: i16,
+    pub rows: i16,
+    pub obstacle_x: i16,
+    pub obstacle_y: i16,
+    pub robot_count: usize,
+    /// "F:1:2 L:3:2 ..." in robot order, obstacle included
+    pub initial_positions: String,
+}
+
+impl ExperimentRow {
+    pub const CSV_HEADER: &'static str = "algorithm_index,algorithm,algorithm_status,rule_count,visibility,experiment_id,status,is_essential,steps_taken,cycle_len,total_activation,total_activation_in_cycle,color_activations,movement_activations,columns,rows,obstacle_x,obstacle_y,robot_count,initial_positions";
+
+    pub fn new(
+        algorithm_index: usize,
+        algorithm: &str,
+        algorithm_status: AlgorithmStatus,
+        rule_count: usize,
+        visibility: i16,
+        status: ExecutionStatus,
+        result: &ExperimentResult,
+    ) -> Self {
+        let grid_experiment = &result.grid_experiment;
+        let grid = &grid_experiment.grid_config;
+        let initial_positions = &grid_experiment.init_config.initial_positions;
+
+        Self {
+            algorithm_index,
+            algorithm: algorithm.to_string(),
+            algorithm_status,
+            rule_count,
+            visibility,
+            experiment_id: result.experiment_id,
+            status,
+            is_essential: grid_experiment.init_config.is_essential,
+            steps_taken: result.steps_taken,
+            cycle_len: result.cycle_len,
+            total_activation: result.total_activation,
+            total_activation_in_cycle: result.total_activation_in_cycle,
+            color_activations: result
+                .robots_metrics
+                .iter()
+                .map(|robot| robot.color_activations)
+                .sum(),
+            movement_activations: result
+                .robots_metrics
+                .iter()
+                .map(|robot| robot.movement_activations)
+                .sum(),
+            columns: grid.columns,
+            rows: grid.rows,
+            obstacle_x: grid.obstacle_position.0,
+            obstacle_y: grid.obstacle_position.1,
+            robot_count: initial_positions
+                .iter()
+                .filter(|(letter, _, _)| *letter != CONFIG.obstacle)
+                .count(),
+            initial_positions: initial_positions
+                .iter()
+                .map(|(letter, x, y)| format!("{}:{}:{}", letter, x, y))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// Same columns as `CSV_HEADER`; enums use their serde names like the JSONL
+    pub fn to_csv_line(&self) -> String {
+        [
+            self.algorithm_index.to_string(),
+            csv_field(&self.algorithm),
+            format!("{:?}", self.algorithm_status),
+            self.rule_count.to_string(),
+            self.visibility.to_string(),
+            self.experiment_id.to_string(),
+            format!("{:?}", self.status),
+            self.is_essential.to_string(),
+            self.steps_taken.to_string(),
+            self.cycle_len.to_string(),
+            self.total_activation.to_string(),
+            self.total_activation_in_cycle.to_string(),
+            self.color_activations.to_string(),
+            self.movement_activations.to_string(),
+            self.columns.to_string(),
+            self.rows.to_string(),
+            self.obstacle_x.to_string(),
+            self.obstacle_y.to_string(),
+            self.robot_count.to_string(),
+            csv_field(&self.initial_positions),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -45,8 +45,85 @@ impl ExplorationHistory {
             println!("{:?}", row);
         }
     }
+    /// Grid cells never visited, as (x, y)
+    pub fn unvisited_cells(&self, config: &GridConfig) -> Vec<(i16, i16)> {
+        let inner_height = self.matrix.len();
+        let mut cells = Vec::new();
+        for (flipped_y, row) in self.matrix.iter().enumerate() {
+            for (inner_x, &visited) in row.iter().enumerate() {
+                if !visited {
+                    let x = config.min_x + 1 + inner_x as i16;
+                    let y = config.min_y + 1 + (inner_height - 1 - flipped_y) as i16;
+                    cells.push((x, y));
+                }
+            }
+        }
+        cells.sort_unstable();
+        cells
+    }
+
     pub fn is_fully_explored(&self) -> bool {
         // Check if all cells in all rows are `true`
         self.matrix.iter().all(|row| row.iter().all(|&cell| cell))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3 columns x 2 rows inside a wall border
+    fn grid() -> GridConfig {
+        GridConfig {
+            columns: 3,
+            rows: 2,
+            min_x: 0,
+            max_x: 4,
+            min_y: 0,
+            max_y: 3,
+            obstacle_position: (2, 1),
+        }
+    }
+
+    #[test]
+    fn rows_are_stored_top_down() {
+        let config = grid();
+        let mut exploration_history = ExplorationHistory::new(&config);
+        assert_eq!(exploration_history.matrix.len(), 2);
+        assert_eq!(exploration_history.matrix[0].len(), 3);
+
+        // Top-left cell: highest y goes to the first row
+        exploration_history.set_positions(&[('F', 1, 2)], &config);
+        assert!(exploration_history.matrix[0][0]);
+        // Bottom-right cell: lowest y goes to the last row
+        exploration_history.set_positions(&[('F', 3, 1)], &config);
+        assert!(exploration_history.matrix[1][2]);
+        assert_eq!(
+            exploration_history.unvisited_cells(&config),
+            vec![(1, 1), (2, 1), (2, 2), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn walls_and_outside_positions_are_ignored() {
+        let config = grid();
+        let mut exploration_history = ExplorationHistory::new(&config);
+        exploration_history.set_positions(
+            &[('W', 0, 1), ('W', 4, 2), ('W', 2, 0), ('W', 2, 3), ('F', 9, 9)],
+            &config,
+        );
+        assert_eq!(exploration_history.unvisited_cells(&config).len(), 6);
+    }
+
+    #[test]
+    fn every_cell_visited_is_fully_explored() {
+        let config = grid();
+        let mut exploration_history = ExplorationHistory::new(&config);
+        for x in 1..=3 {
+            assert!(!exploration_history.is_fully_explored());
+            exploration_history.set_positions(&[('F', x, 1), ('L', x, 2)], &config);
+        }
+        assert!(exploration_history.is_fully_explored());
+        assert!(exploration_history.unvisited_cells(&config).is_empty());
+    }
+}
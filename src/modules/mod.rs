@@ -4,8 +4,10 @@ pub mod algorithm_manager;
 pub mod algorithm_snapshot;
 pub mod algorithm_stats;
 pub mod algorithm_status;
+pub mod algorithm_validation_record;
 pub mod blocked_config_summary;
 pub mod cache_helpers;
+pub mod catalog;
 pub mod classification;
 pub mod color;
 pub mod combination_mode;
@@ -16,8 +18,12 @@ pub mod direction;
 pub mod dist_algo_simulator;
 pub mod draft_rules;
 pub mod dual_position;
+pub mod execution_event;
 pub mod execution_logger;
+pub mod execution_manifest;
 pub mod execution_status;
+pub mod execution_trace;
+pub mod experiment_row;
 pub mod exploration_history;
 pub mod exploration_result;
 pub mod final_rule;
@@ -30,17 +36,28 @@ pub mod grid_experiment;
 pub mod grid_size_generator;
 pub mod init_config;
 pub mod locally_defined;
+pub mod model_check;
 pub mod movement_direction;
 pub mod opacity_validator;
 pub mod parallel_rules;
 pub mod position;
+pub mod regression_baseline;
 pub mod progress_helper;
 pub mod rule;
+pub mod rule_coverage;
+pub mod rule_minimality;
+pub mod scaling;
+pub mod shard_result;
+pub mod shard_spec;
 pub mod simulation_config;
 pub mod simulator;
+pub mod state_graph;
+pub mod streaming_combination;
 pub mod time_helper;
 pub mod validation_config;
+pub mod validation_policy;
 pub mod validation_progress_bars;
 pub mod view;
+pub mod viewer_assets;
 pub mod web_algo_generator;
 pub mod yaml_algo_generator;
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::position::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModelCheckVerdict {
+    /// Every reachable cycle covers its grid
+    Proved,
+    /// Some reachable cycle never visits some cells
+    Refuted,
+}
+
+/// A reachable cycle that never visits some cells, with the start reaching it soonest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCheckViolation {
+    pub columns: i16,
+    pub rows: i16,
+    pub obstacle_position: (i16, i16),
+    /// Obstacle first, then the robots
+    pub initial_positions: Vec<Position>,
+    /// Rounds from the start until the first state of the cycle
+    pub rounds: usize,
+    pub cycle_len: usize,
+    /// Cells the cycle never visits, as (x, y)
+    pub unvisited_cells: Vec<(i16, i16)>,
+}
+
+/// Result of `--model-check`, written to `_model_check/<stem>_model_check.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCheckReport {
+    pub algorithm: String,
+    pub visibility: i16,
+    pub max_columns: i16,
+    pub max_rows: i16,
+    /// Grid sizes and obstacle placements with room for the robots
+    pub grids_checked: usize,
+    pub starts_checked: usize,
+    pub states_explored: usize,
+    pub cycles_found: usize,
+    /// Starts whose execution blocks before any cycle (not part of the property)
+    pub blocked_starts: usize,
+    pub verdict: ModelCheckVerdict,
+    /// Ordered by grid area, then rounds: the first one is the counterexample
+    pub violations: Vec<ModelCheckViolation>,
+    /// Trace file of the smallest violation
+    pub counterexample: Option<String>,
+}
@@ -9,3 +9,57 @@ pub fn rotate_point(x: &i16, y: &i16, angle: &i16) -> (i16, i16) {
         _ => panic!("Angle must be 0, 90, 180, or 270"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANGLES: [i16; 4] = [0, 90, 180, 270];
+
+    fn points() -> impl Iterator<Item = (i16, i16)> {
+        (-4..=4).flat_map(|x| (-4..=4).map(move |y| (x, y)))
+    }
+
+    #[test]
+    fn rotate_point_quarter_turns_are_clockwise() {
+        assert_eq!(rotate_point(&0, &1, &90), (1, 0));
+        assert_eq!(rotate_point(&1, &0, &90), (0, -1));
+        assert_eq!(rotate_point(&0, &1, &180), (0, -1));
+        assert_eq!(rotate_point(&0, &1, &270), (-1, 0));
+        assert_eq!(rotate_point(&2, &-3, &0), (2, -3));
+    }
+
+    #[test]
+    fn rotating_four_times_is_the_identity() {
+        for (x, y) in points() {
+            for angle in ANGLES {
+                let mut point = (x, y);
+                for _ in 0..4 {
+                    point = rotate_point(&point.0, &point.1, &angle);
+                }
+                assert_eq!(point, (x, y), "angle {}", angle);
+            }
+        }
+    }
+
+    #[test]
+    fn rotations_compose_and_preserve_distance() {
+        for (x, y) in points() {
+            for first in ANGLES {
+                for second in ANGLES {
+                    let (rx, ry) = rotate_point(&x, &y, &first);
+                    let composed = rotate_point(&rx, &ry, &second);
+                    assert_eq!(composed, rotate_point(&x, &y, &((first + second) % 360)));
+                }
+                let (rx, ry) = rotate_point(&x, &y, &first);
+                assert_eq!(rx.abs() + ry.abs(), x.abs() + y.abs());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Angle must be 0, 90, 180, or 270")]
+    fn rotate_point_rejects_other_angles() {
+        rotate_point(&1, &0, &45);
+    }
+}
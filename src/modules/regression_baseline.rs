@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{algorithm_status::AlgorithmStatus, execution_status::ExecutionStatus};
+
+/// Expected outcome of one experiment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineExperiment {
+    pub experiment_id: usize,
+    pub status: ExecutionStatus,
+    pub steps_taken: usize,
+    pub cycle_len: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineAlgorithm {
+    pub file: String,
+    pub status: AlgorithmStatus,
+    /// Left empty in a hand-written manifest to check the status only
+    #[serde(default)]
+    pub experiments: Vec<BaselineExperiment>,
+}
+
+/// Expected-status manifest of `--regress`, one entry per algorithm file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegressionBaseline {
+    /// Number of validation experiments when the baseline was recorded
+    #[serde(default)]
+    pub experiment_count: Option<usize>,
+    pub algorithms: Vec<BaselineAlgorithm>,
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    algorithm_experiments_modules::experiment_result::ExperimentResult,
+    algorithm_status::AlgorithmStatus, execution_status::ExecutionStatus,
+};
+
+/// Rule fires of one experiment, only the rules that fired (rule index → count)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentRuleFires {
+    pub experiment_id: usize,
+    pub is_essential: bool,
+    pub status: ExecutionStatus,
+    pub fires: BTreeMap<usize, usize>,
+    pub fires_in_cycle: BTreeMap<usize, usize>,
+}
+
+/// How often each rule of one algorithm fired during validation, one line of `rule_coverage.jsonl`.
+/// Skipped experiments are left out, so with an early-exit policy the counts cover
+/// only the experiments that ran.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleCoverage {
+    pub algorithm_index: usize,
+    pub algorithm: String,
+    pub status: AlgorithmStatus,
+    /// Rule diagrams as written in `.web-algo` files, by final rule index
+    pub rules: Vec<String>,
+    pub experiments_run: usize,
+    pub total_fires: Vec<usize>,
+    /// Fires in essential configurations only
+    pub essential_fires: Vec<usize>,
+    /// Fires during the final cycle of each experiment
+    pub cycle_fires: Vec<usize>,
+    /// Rules that never fired
+    pub dead_rules: Vec<usize>,
+    /// Rules that fired, but only in non-essential configurations
+    pub non_essential_only: Vec<usize>,
+    /// Rules that fired in experiments ending in a cycle, but never inside that cycle
+    /// (blocked and timed out experiments have no cycle and are left out)
+    pub before_cycle_only: Vec<usize>,
+    pub experiment_fires: Vec<ExperimentRuleFires>,
+}
+
+impl RuleCoverage {
+    pub fn new(
+        algorithm_index: usize,
+        algorithm: &str,
+        status: AlgorithmStatus,
+        rules: Vec<String>,
+        experiment_results: &[ExperimentResult],
+    ) -> Self {
+        let rule_count = rules.len();
+        let mut total_fires = vec![0; rule_count];
+        let mut essential_fires = vec![0; rule_count];
+        let mut cycle_fires = vec![0; rule_count];
+        let mut cycling_experiment_fires = vec![0; rule_count];
+        let mut experiment_fires = Vec::new();
+
+        for result in experiment_results
+            .iter()
+            .filter(|result| result.status != ExecutionStatus::Skipped)
+        {
+            let is_essential = result.grid_experiment.init_config.is_essential;
+            for (rule_index, &count) in result.rule_fires.iter().enumerate() {
+                total_fires[rule_index] += count;
+                if is_essential {
+                    essential_fires[rule_index] += count;
+                }
+                if result.cycle_len > 0 {
+                    cycling_experiment_fires[rule_index] += count;
+                }
+            }
+            for (rule_index, &count) in result.rule_fires_in_cycle.iter().enumerate() {
+                cycle_fires[rule_index] += count;
+            }
+            experiment_fires.push(ExperimentRuleFires {
+                experiment_id: result.experiment_id,
+                is_essential,
+                status: result.status.clone(),
+                fires: sparse(&result.rule_fires),
+                fires_in_cycle: sparse(&result.rule_fires_in_cycle),
+            });
+        }
+
+        let fired = |rule_index: &usize| total_fires[*rule_index] > 0;
+        let dead_rules = (0..rule_count).filter(|index| !fired(index)).collect();
+        let non_essential_only = (0..rule_count)
+            .filter(|index| fired(index) && essential_fires[*index] == 0)
+            .collect();
+        let before_cycle_only = (0..rule_count)
+            .filter(|index| cycling_experiment_fires[*index] > 0 && cycle_fires[*index] == 0)
+            .collect();
+
+        Self {
+            algorithm_index,
+            algorithm: algorithm.to_string(),
+            status,
+            rules,
+            experiments_run: experiment_fires.len(),
+            total_fires,
+            essential_fires,
+            cycle_fires,
+            dead_rules,
+            non_essential_only,
+            before_cycle_only,
+            experiment_fires,
+        }
+    }
+}
+
+fn sparse(counts: &[usize]) -> BTreeMap<usize, usize> {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(index, count)| (index, *count))
+        .collect()
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::algorithm_status::AlgorithmStatus;
+
+/// A rule subset that still validates, written as its own `.web-algo`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinimalSubset {
+    /// How the subset was found: "fired_only" or "greedy_<order>"
+    pub strategy: String,
+    /// Final rule indices kept, in rule order
+    pub rules: Vec<usize>,
+    pub status: AlgorithmStatus,
+    pub file: String,
+}
+
+/// Which rules of a validated algorithm are needed, written to `_minimal/<stem>_minimality.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleMinimalityReport {
+    pub algorithm: String,
+    pub status: AlgorithmStatus,
+    pub visibility: i16,
+    pub rule_count: usize,
+    /// Times each rule fired over all experiments, by final rule index
+    pub rule_fires: Vec<usize>,
+    /// Rules that never fired: dropping them cannot change any run
+    pub never_fired: Vec<usize>,
+    /// Fired rules whose removal alone keeps the algorithm validated
+    pub droppable_alone: Vec<usize>,
+    /// Fired rules whose removal alone breaks the algorithm
+    pub necessary: Vec<usize>,
+    pub minimal_subsets: Vec<MinimalSubset>,
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::execution_status::ExecutionStatus;
+
+/// One run of the scaling analysis, a line of `<stem>_scaling.csv`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingRow {
+    pub columns: i16,
+    pub rows: i16,
+    pub area: i32,
+    pub obstacle_x: i16,
+    pub obstacle_y: i16,
+    /// Index in `initial_configurations`
+    pub initial_positions_index: usize,
+    pub is_essential: bool,
+    pub status: ExecutionStatus,
+    /// First round after which every cell has been visited, if any
+    pub rounds_to_cover: Option<usize>,
+    pub steps_taken: usize,
+    pub cycle_len: usize,
+}
+
+impl ScalingRow {
+    pub const CSV_HEADER: &'static str = "columns,rows,area,obstacle_x,obstacle_y,initial_positions_index,is_essential,status,rounds_to_cover,steps_taken,cycle_len";
+
+    /// Same columns as `CSV_HEADER`; `rounds_to_cover` is empty when the grid was never covered
+    pub fn to_csv_line(&self) -> String {
+        [
+            self.columns.to_string(),
+            self.rows.to_string(),
+            self.area.to_string(),
+            self.obstacle_x.to_string(),
+            self.obstacle_y.to_string(),
+            self.initial_positions_index.to_string(),
+            self.is_essential.to_string(),
+            format!("{:?}", self.status),
+            self.rounds_to_cover
+                .map(|rounds| rounds.to_string())
+                .unwrap_or_default(),
+            self.steps_taken.to_string(),
+            self.cycle_len.to_string(),
+        ]
+        .join(",")
+    }
+}
+
+/// Worst case over the validated runs of one grid
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridScaling {
+    pub columns: i16,
+    pub rows: i16,
+    pub area: i32,
+    pub runs: usize,
+    pub validated: usize,
+    pub max_rounds_to_cover: Option<usize>,
+    pub max_cycle_len: Option<usize>,
+}
+
+/// Least-squares fits of a metric against the grid area (rows × columns):
+/// `y = slope · area + intercept`, and `y = coefficient · area^exponent` in log-log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingFit {
+    pub metric: String,
+    pub points: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+    /// The log-log fields are `None` with fewer than two distinct positive areas
+    pub coefficient: Option<f64>,
+    /// Close to 1 for O(n·m), larger when the metric blows up with the grid
+    pub exponent: Option<f64>,
+    pub log_r_squared: Option<f64>,
+}
+
+impl ScalingFit {
+    /// `None` with fewer than two distinct areas
+    pub fn fit(metric: &str, points: &[(f64, f64)]) -> Option<Self> {
+        let (slope, intercept, r_squared) = least_squares(points)?;
+        let log_points: Vec<(f64, f64)> = points
+            .iter()
+            .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+            .map(|(x, y)| (x.ln(), y.ln()))
+            .collect();
+        let power_law = least_squares(&log_points);
+        Some(Self {
+            metric: metric.to_string(),
+            points: points.len(),
+            slope,
+            intercept,
+            r_squared,
+            coefficient: power_law.map(|(_, log_coefficient, _)| log_coefficient.exp()),
+            exponent: power_law.map(|(exponent, _, _)| exponent),
+            log_r_squared: power_law.map(|(_, _, log_r_squared)| log_r_squared),
+        })
+    }
+}
+
+/// (slope, intercept, r²) of the least-squares line through `points`
+fn least_squares(points: &[(f64, f64)]) -> Option<(f64, f64, f64)> {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if points.len() < 2 || sxx == 0.0 {
+        return None;
+    }
+    let sxy: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let syy: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    // A constant metric is fitted exactly
+    let r_squared = if syy == 0.0 { 1.0 } else { sxy * sxy / (sxx * syy) };
+    Some((slope, intercept, r_squared))
+}
+
+/// How rounds-to-cover and cycle length grow with the grid, written to
+/// `_scaling/<stem>_scaling.json` next to the per-run CSV
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingReport {
+    pub algorithm: String,
+    pub visibility: i16,
+    pub min_side: i16,
+    pub max_side: i16,
+    pub grids: Vec<GridScaling>,
+    /// Fitted on the worst case of each grid
+    pub rounds_to_cover_fit: Option<ScalingFit>,
+    pub cycle_len_fit: Option<ScalingFit>,
+    pub csv: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_squares_fits_lines_and_rejects_single_areas() {
+        let (slope, intercept, r_squared) =
+            least_squares(&[(1.0, 5.0), (2.0, 7.0), (4.0, 11.0)]).unwrap();
+        assert!((slope - 2.0).abs() < 1e-12);
+        assert!((intercept - 3.0).abs() < 1e-12);
+        assert!((r_squared - 1.0).abs() < 1e-12);
+
+        assert_eq!(least_squares(&[(1.0, 4.0), (3.0, 4.0)]), Some((0.0, 4.0, 1.0)));
+        let (_, _, noisy_r_squared) =
+            least_squares(&[(1.0, 1.0), (2.0, 3.0), (3.0, 2.0)]).unwrap();
+        assert!(noisy_r_squared > 0.0 && noisy_r_squared < 1.0);
+
+        assert_eq!(least_squares(&[]), None);
+        assert_eq!(least_squares(&[(2.0, 1.0)]), None);
+        assert_eq!(least_squares(&[(2.0, 1.0), (2.0, 3.0)]), None);
+    }
+
+    #[test]
+    fn fit_without_a_power_law_round_trips_through_json() {
+        let fit = ScalingFit::fit("rounds_to_cover", &[(0.0, 0.0), (4.0, 6.0)]).unwrap();
+        assert!((fit.slope - 1.5).abs() < 1e-12);
+        assert_eq!(fit.exponent, None);
+        assert_eq!(fit.coefficient, None);
+
+        let json = serde_json::to_string(&fit).unwrap();
+        let parsed: ScalingFit = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.exponent, None);
+        assert_eq!(parsed.r_squared, fit.r_squared);
+
+        let fit = ScalingFit::fit("cycle_len", &[(4.0, 8.0), (16.0, 32.0)]).unwrap();
+        assert!((fit.exponent.unwrap() - 1.0).abs() < 1e-12);
+        assert!((fit.coefficient.unwrap() - 2.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    algorithm_validation_record::AlgorithmValidationRecord,
+    blocked_config_summary::BlockedConfigSummary, grid_experiment::GridExperiment,
+    shard_spec::ShardSpec,
+};
+
+/// Everything a shard run produces, written as `shard_result.json` so that
+/// `merge_shards` can rebuild the folder-level logs of a single-process run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardResult {
+    pub shard: ShardSpec,
+    /// Names of ALL algorithm files in the folder (not only this shard's)
+    pub algo_file_names: Vec<String>,
+    /// `config_fingerprint` of the config.json the shard ran with
+    #[serde(default)]
+    pub config_fingerprint: String,
+    pub grid_experiments: Vec<GridExperiment>,
+    pub records: Vec<AlgorithmValidationRecord>,
+    pub blocked_summaries: Vec<BlockedConfigSummary>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Selects a deterministic subset of algorithm files: shard `index` of `count`.
+/// Files are assigned round-robin over the sorted file list (`i % count == index`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardSpec {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl ShardSpec {
+    /// Parses the `i/n` form used on the command line (zero-based `i`, `i < n`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let (index, count) = value.split_once('/')?;
+        let index = index.trim().parse::<usize>().ok()?;
+        let count = count.trim().parse::<usize>().ok()?;
+        if count == 0 || index >= count {
+            return None;
+        }
+        Some(Self { index, count })
+    }
+
+    pub fn contains(&self, algo_index: usize) -> bool {
+        algo_index % self.count == self.index
+    }
+
+    /// Folder name used for this shard's output, e.g. `shard_0_of_4`
+    pub fn folder_name(&self) -> String {
+        format!("shard_{}_of_{}", self.index, self.count)
+    }
+}
@@ -618,9 +618,19 @@ pub fn simulation(
         execution_positions_result.push(sorted_positions);
         activations_counts_result.push(sorted_activations);*/
 
+        // Executions are pushed in rayon scheduling order: sort them (with their
+        // positions) so goal files and combinations do not depend on it
+        let mut sorted: Vec<(Vec<usize>, Vec<Vec<Position>>)> = executions_guard
+            .iter()
+            .cloned()
+            .zip(positions_guard.iter().cloned())
+            .collect();
+        sorted.sort_unstable();
+        let (sorted_executions, sorted_positions): (Vec<_>, Vec<_>) = sorted.into_iter().unzip();
+
         // without filtering
-        executions_result.push(executions_guard.clone());
-        execution_positions_result.push(positions_guard.clone());
+        executions_result.push(sorted_executions);
+        execution_positions_result.push(sorted_positions);
     }
 
     (executions_result, execution_positions_result)
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{grid_config::GridConfig, position::Position};
+
+/// A global state: every robot's color and position (obstacle included), sorted,
+/// since robots are anonymous
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateNode {
+    pub id: usize,
+    pub state: Vec<Position>,
+    /// Experiment ids starting in this state
+    pub initial_experiments: Vec<usize>,
+    /// No robot matched a rule: the execution stops here
+    pub blocked: bool,
+    /// Index in `StateGraph::cycles` when the state is on a cycle
+    pub cycle: Option<usize>,
+}
+
+/// One FSYNC round
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StateEdge {
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateCycle {
+    pub id: usize,
+    /// Node ids in round order
+    pub nodes: Vec<usize>,
+    /// The robots visit every cell of the grid along the cycle
+    pub explores: bool,
+}
+
+/// Configuration graph an algorithm induces on one `GridConfig`, restricted to the
+/// states reachable from the validation initial configurations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateGraph {
+    pub algorithm: String,
+    pub grid_index: usize,
+    pub grid_config: GridConfig,
+    pub visibility: i16,
+    pub nodes: Vec<StateNode>,
+    pub edges: Vec<StateEdge>,
+    pub cycles: Vec<StateCycle>,
+}
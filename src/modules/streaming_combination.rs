@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Settings of the streaming combiner (`methodology::streaming_combiner`).
+/// When present in the config, global algorithms are enumerated goal by goal (DFS)
+/// instead of materializing the cross product after each goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamingCombination {
+    /// Stop once this many algorithms have been kept (`None` = enumerate everything)
+    #[serde(default)]
+    pub max_algorithms: Option<usize>,
+    /// Keep one algorithm out of every `sample_every` produced (1 = keep all)
+    #[serde(default = "default_sample_every")]
+    pub sample_every: usize,
+}
+
+fn default_sample_every() -> usize {
+    1
+}
+
+impl Default for StreamingCombination {
+    fn default() -> Self {
+        StreamingCombination {
+            max_algorithms: None,
+            sample_every: default_sample_every(),
+        }
+    }
+}
@@ -7,8 +7,11 @@ use std::time::Instant;
 /// - 3661.5 sec -> "1h 1min 1.5 sec"
 /// - 10923.2222 sec -> "3h 2min 3.2 sec"
 pub fn format_elapsed_time(start_time: Instant) -> String {
-    let seconds = start_time.elapsed().as_secs_f32();
+    format_duration_secs(start_time.elapsed().as_secs_f32())
+}
 
+/// Same format as `format_elapsed_time` for a duration read back from a log
+pub fn format_duration_secs(seconds: f32) -> String {
     let total_seconds = seconds as u64;
     let remaining_seconds = seconds - total_seconds as f32;
 
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    algorithm_experiments_modules::algorithm_metrics::AlgorithmMetrics,
+    execution_status::ExecutionStatus,
+};
+
+/// Decides when `process_algo` may stop running the remaining `GridExperiment`s.
+/// Skipped experiments are reported as `ExecutionStatus::Skipped`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ValidationPolicy {
+    /// Run every experiment (complete per-config report)
+    #[default]
+    RunAll,
+    /// Stop at the first failure (blocked, cyclic or timeout) of an essential configuration.
+    /// The algorithm is doomed, but the reported status may be less severe than a full run.
+    StopOnEssentialFailure,
+    /// Stop once no remaining outcome can change the status chosen by
+    /// `determine_algorithm_status`. The reported status is the same as a full run.
+    StopWhenStatusFixed,
+}
+
+impl ValidationPolicy {
+    /// Returns true if the experiments left after `last_outcome` can be skipped
+    pub fn should_stop(
+        &self,
+        last_outcome: &ExecutionStatus,
+        is_essential: bool,
+        metrics: &AlgorithmMetrics,
+    ) -> bool {
+        match self {
+            ValidationPolicy::RunAll => false,
+            ValidationPolicy::StopOnEssentialFailure => {
+                is_essential
+                    && matches!(
+                        last_outcome,
+                        ExecutionStatus::Blocked | ExecutionStatus::Cycle | ExecutionStatus::Timeout
+                    )
+            }
+            // Timeout has the highest priority. simulate_exploration has no step limit and
+            // never reports it, so a cycle is already the highest reachable priority.
+            ValidationPolicy::StopWhenStatusFixed => metrics.timeout > 0 || metrics.cyclic > 0,
+        }
+    }
+}
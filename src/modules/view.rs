@@ -46,19 +46,10 @@ fn find_last_robot_position(view: &View) -> Option<Position> {
         .cloned()
 }
 
+/// Keeps the first view of every rotation class, in the original order
 pub fn remove_identical_views(views: &mut Vec<View>) {
-    let mut i = 0;
-    while i < views.len() {
-        let mut j = i + 1;
-        while j < views.len() {
-            if are_equivalent_with_rotation(&views[i], &views[j]) {
-                views.remove(j);
-            } else {
-                j += 1;
-            }
-        }
-        i += 1;
-    }
+    let mut seen: HashSet<View> = HashSet::with_capacity(views.len());
+    views.retain(|view| seen.insert(canonical_view(view)));
 }
 
 pub fn distribute_robot_colors_iterative(views: &mut Vec<View>, colors: &Vec<char>) {
@@ -90,6 +81,23 @@ pub fn rotate_view(view: &View, angle: i16) -> View {
         .collect()
 }
 
+/// Canonical form of a view under rotation: the lexicographically smallest of its four
+/// sorted rotations. Two views are `are_equivalent_with_rotation` iff their canonical
+/// forms are equal (views never contain the same position twice).
+pub fn canonical_view(view: &View) -> View {
+    [0, 90, 180, 270]
+        .iter()
+        .map(|&angle| sorted_view(&rotate_view(view, angle)))
+        .min()
+        .unwrap()
+}
+
+fn sorted_view(view: &View) -> View {
+    let mut sorted = view.clone();
+    sorted.sort_unstable();
+    sorted
+}
+
 pub fn are_equivalent_with_rotation(view_1: &View, view_2: &View) -> bool {
     let rotations = vec![0, 90, 180, 270];
     for angle in rotations {
@@ -145,17 +153,17 @@ pub fn has_symmetrical_rotation(view: &View, opcity: bool, visibility: i16) -> b
         distribute_abstract_positions(&mut view_copy, visibility);
     }
     let rotations = [
-        view_copy.clone(), // Original view
-        rotate_view(&view_copy, 90),
-        rotate_view(&view_copy, 180),
-        rotate_view(&view_copy, 270),
+        sorted_view(&view_copy), // Original view
+        sorted_view(&rotate_view(&view_copy, 90)),
+        sorted_view(&rotate_view(&view_copy, 180)),
+        sorted_view(&rotate_view(&view_copy, 270)),
     ];
 
     // Compare each rotation with the rest
     for i in 0..rotations.len() - 1 {
         // Skip the last item
         for j in (i + 1)..rotations.len() {
-            if are_equivalent(&rotations[i], &rotations[j]) {
+            if rotations[i] == rotations[j] {
                 return false; // Found an identical view
             }
         }
@@ -200,11 +208,8 @@ pub fn remove_existed_views(generated_views: &mut Vec<View>, existed_views: &Vec
          }
 
     } */
-    generated_views.retain(|gen_view| {
-        !existed_views
-            .iter()
-            .any(|ex_view| are_equivalent_with_rotation(gen_view, ex_view))
-    });
+    let existed: HashSet<View> = existed_views.iter().map(canonical_view).collect();
+    generated_views.retain(|gen_view| !existed.contains(&canonical_view(gen_view)));
 }
 
 pub fn exists_in_view(i: &i16, j: &i16, view: &Vec<Position>) -> bool {
@@ -238,3 +243,134 @@ pub fn display_view(view: &View, visibility: &i16) {
         println!();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methodology::view::{distribute_obstacles, generate_views};
+
+    // Pairwise implementations the canonical-form versions replaced, kept as reference.
+
+    fn remove_identical_views_pairwise(views: &mut Vec<View>) {
+        let mut i = 0;
+        while i < views.len() {
+            let mut j = i + 1;
+            while j < views.len() {
+                if are_equivalent_with_rotation(&views[i], &views[j]) {
+                    views.remove(j);
+                } else {
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    fn has_symmetrical_rotation_pairwise(view: &View) -> bool {
+        let rotations = [
+            view.clone(),
+            rotate_view(view, 90),
+            rotate_view(view, 180),
+            rotate_view(view, 270),
+        ];
+        for i in 0..rotations.len() - 1 {
+            for j in (i + 1)..rotations.len() {
+                if are_equivalent(&rotations[i], &rotations[j]) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn generate_views_pairwise(colors: &Vec<char>, num_robots: usize, visibility: i16) -> Vec<View> {
+        let mut views = generate_robot_view(vec![vec![('r', 0, 0)]], num_robots - 1, visibility);
+        remove_identical_views_pairwise(&mut views);
+        distribute_robot_colors_iterative(&mut views, colors);
+        views = distribute_obstacles(&views, visibility);
+        views.retain(has_symmetrical_rotation_pairwise);
+        remove_identical_views_pairwise(&mut views);
+        views
+    }
+
+    #[test]
+    fn generate_views_matches_pairwise_reference() {
+        // The reference only covers the transparent case
+        let colors = vec!['L', 'F'];
+        let expected = generate_views_pairwise(&colors, 3, 2);
+        let actual = generate_views(&colors, 3, 2, false);
+
+        assert!(!expected.is_empty());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonical_view_is_rotation_invariant() {
+        let view: View = vec![('L', 0, 0), ('F', 1, 0), ('O', 0, -2)];
+        let canonical = canonical_view(&view);
+        for angle in [90, 180, 270] {
+            assert_eq!(canonical_view(&rotate_view(&view, angle)), canonical);
+        }
+        let mirrored: View = vec![('L', 0, 0), ('F', -1, 0), ('O', 0, -2)];
+        assert_ne!(canonical_view(&mirrored), canonical);
+    }
+
+    #[test]
+    fn remove_existed_views_matches_pairwise_reference() {
+        let mut generated = generate_robot_view(vec![vec![('r', 0, 0)]], 2, 2);
+        let existed: Vec<View> = generated
+            .iter()
+            .step_by(3)
+            .map(|view| rotate_view(view, 90))
+            .collect();
+
+        let mut expected = generated.clone();
+        expected.retain(|gen_view| {
+            !existed
+                .iter()
+                .any(|ex_view| are_equivalent_with_rotation(gen_view, ex_view))
+        });
+        remove_existed_views(&mut generated, &existed);
+
+        assert_eq!(generated, expected);
+    }
+
+    fn colored_views() -> Vec<View> {
+        let mut views = generate_robot_view(vec![vec![('r', 0, 0)]], 2, 1);
+        distribute_robot_colors_iterative(&mut views, &vec!['L', 'F']);
+        views
+    }
+
+    #[test]
+    fn rotating_a_view_four_times_is_the_identity() {
+        for view in colored_views() {
+            for angle in [0, 90, 180, 270] {
+                let mut rotated = view.clone();
+                for _ in 0..4 {
+                    rotated = rotate_view(&rotated, angle);
+                }
+                assert_eq!(rotated, view);
+            }
+        }
+    }
+
+    #[test]
+    fn rotation_equivalence_is_invariant_under_rotation() {
+        let views = colored_views();
+        assert!(views.len() > 10);
+        for view_1 in &views {
+            for view_2 in &views {
+                let equivalent = are_equivalent_with_rotation(view_1, view_2);
+                assert_eq!(are_equivalent_with_rotation(view_2, view_1), equivalent);
+                assert_eq!(
+                    canonical_view(view_1) == canonical_view(view_2),
+                    equivalent
+                );
+                for angle in [90, 180, 270] {
+                    let rotated = rotate_view(view_2, angle);
+                    assert_eq!(are_equivalent_with_rotation(view_1, &rotated), equivalent);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// Stylesheets bundled into the binary and inlined into every generated HTML
+// viewer, so the pages render without network access (air-gapped machines,
+// archived results).
+
+const VIEWER_CSS: &str = include_str!("../assets/viewer.css");
+const UTILITY_CSS: &str = include_str!("../assets/utility.css");
+
+/// `<style>` block with the Bootstrap subset and icons used by the viewers
+pub fn viewer_styles() -> String {
+    format!("<style>\n{}</style>", VIEWER_CSS)
+}
+
+/// `<style>` block with the Tailwind subset used by the initial configurations viewer
+pub fn utility_styles() -> String {
+    format!("<style>\n{}</style>", UTILITY_CSS)
+}
@@ -1,5 +1,32 @@
 use std::{collections::HashMap, fs, io::Write};
 
+use crate::methodology::{configuration::CONFIG, globals::get_number_of_colors};
+
+/// Layout of every generated `.web-algo` file; `{rules}` is filled by `add_rule`
+pub const WEB_ALGO_TEMPLATE: &str = r"****** OPTIONS ******
+version: 1
+walls:
+  - - {wall_x0}
+    - {wall_y0}
+    - 0
+  - - {wall_x1}
+    - {wall_y1}
+    - 2
+chirality: true
+visibilityRange: {visibility}
+colors:
+{colors}
+dimension: 2
+
+****** INITIAL CONFIGURATIONS ******
+{initial_configurations}
+
+****** RULES ******
+@alias X {alias_x}
+
+{rules}
+";
+
 pub struct WebAlgoGenerator {
     filename: String,
     output_dir: String,
@@ -17,6 +44,25 @@ impl WebAlgoGenerator {
         }
     }
 
+    /// Generator for `WEB_ALGO_TEMPLATE` with the options, walls, colors and initial
+    /// configurations taken from `CONFIG`
+    pub fn with_config(filename: &str, output_dir: &str, visibility: i16) -> Self {
+        let mut generator = Self::new(filename, output_dir, WEB_ALGO_TEMPLATE);
+        generator.set("visibility", &visibility.to_string());
+        // Set wall positions from constants
+        generator.set("wall_x0", &CONFIG.web_algo_walls[0][0].to_string());
+        generator.set("wall_y0", &CONFIG.web_algo_walls[0][1].to_string());
+        generator.set("wall_x1", &CONFIG.web_algo_walls[1][0].to_string());
+        generator.set("wall_y1", &CONFIG.web_algo_walls[1][1].to_string());
+        generator.set("colors", &CONFIG.web_algo_colors);
+        generator.set(
+            "initial_configurations",
+            &CONFIG.web_algo_initial_configuration,
+        );
+        generator.set("alias_x", &build_alias_x());
+        generator
+    }
+
     pub fn set(&mut self, key: &str, value: &str) {
         self.data.insert(key.to_string(), value.to_string());
     }
@@ -44,3 +90,21 @@ impl WebAlgoGenerator {
         .unwrap_or_else(|e| eprintln!("Failed to write file: {}", e));
     }
 }
+
+fn build_alias_x() -> String {
+    let mut alias = vec!['.', 'W', 'O']; // fixed ones
+    let num_colors = get_number_of_colors();
+
+    // take only as many as needed from ALL_COLOR_LETTERS
+    alias.extend_from_slice(
+        &CONFIG.all_color_letters[..num_colors.min(CONFIG.all_color_letters.len())],
+    );
+
+    // turn into string like "{.,W,O,F,L,G}"
+    let joined: String = alias
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{{}}}", joined)
+}
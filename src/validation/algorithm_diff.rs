@@ -0,0 +1,286 @@
+// ============================================================================
+// ALGORITHM DIFF
+// ============================================================================
+//
+// Compares two algorithm files. They are equivalent when some permutation of
+// the robot colors maps the rules of A, each up to rotation, onto the rules
+// of B (`canonical_rule`, as in the generation dedup). Otherwise the rules are
+// diffed under the permutation that matches the most of them: rules only in
+// A, rules only in B, and views both have but with different actions.
+//
+// `canonical_hash` names the equivalence class. The colors the rules use are
+// relabeled 0, 1, … in each possible order; the canonical form is the smallest
+// sorted list of rotation-canonical rules over those relabelings, so it does
+// not depend on the color letters or on the configured colors. Its text form
+// is hashed with SHA-256, stable across builds and platforms.
+//
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    methodology::{
+        configuration::CONFIG,
+        simulator::generate_web_algo_string_rule,
+        switching_colors_validator::{
+            apply_color_permutation_to_color, apply_color_permutation_to_view, canonical_rule,
+            CanonicalRule,
+        },
+    },
+    modules::{direction::Direction, final_rule::FinalRule, view::View},
+    validation::algorithm_loader::{load_final_rules, AlgorithmPath},
+};
+
+/// Canonical view → (action, rule index in its file) for every rule with that view
+type RulesByView = BTreeMap<View, Vec<((Direction, char), usize)>>;
+
+/// Prints whether `path_a` and `path_b` are equivalent and, if not, their rule diff.
+/// `None` when a file cannot be read, otherwise whether they are equivalent.
+pub fn diff_algorithms(path_a: &str, path_b: &str) -> Option<bool> {
+    let (rules_a, visibility_a) = read_rules(path_a)?;
+    let (rules_b, visibility_b) = read_rules(path_b)?;
+    let name_a = AlgorithmPath::new(Path::new(path_a)).name;
+    let name_b = AlgorithmPath::new(Path::new(path_b)).name;
+
+    println!("A: {} ({} rules, visibility {})", name_a, rules_a.len(), visibility_a);
+    println!("B: {} ({} rules, visibility {})", name_b, rules_b.len(), visibility_b);
+    if visibility_a != visibility_b {
+        println!("❌ Not equivalent: visibility ranges differ");
+        return Some(false);
+    }
+
+    let colors = robot_colors(&rules_a, &rules_b, CONFIG.obstacle);
+    let by_view_b = rules_by_view(&rules_b, &colors, &colors);
+
+    // Identity first, so it wins ties
+    let mut best: Option<(Vec<char>, RulesByView, usize)> = None;
+    for permutation in colors.iter().cloned().permutations(colors.len()) {
+        let by_view_a = rules_by_view(&rules_a, &permutation, &colors);
+        if actions_only(&by_view_a) == actions_only(&by_view_b) {
+            println!(
+                "✅ Equivalent up to rotation under color permutation {}",
+                format_permutation(&colors, &permutation)
+            );
+            return Some(true);
+        }
+        let matched = matched_rules(&by_view_a, &by_view_b);
+        if best.as_ref().map_or(true, |(_, _, best_matched)| matched > *best_matched) {
+            best = Some((permutation, by_view_a, matched));
+        }
+    }
+
+    let (permutation, by_view_a, matched) = best.expect("at least the identity permutation");
+    println!(
+        "❌ Not equivalent. Closest color permutation {}: {} rules in common",
+        format_permutation(&colors, &permutation),
+        matched
+    );
+    print_rule_diff(&by_view_a, &by_view_b, &rules_a, &rules_b, visibility_a);
+    Some(false)
+}
+
+/// Hex SHA-256, equal for algorithms `diff_algorithms` reports as equivalent.
+/// `obstacle` is the obstacle letter, never relabeled like the robot colors.
+pub fn canonical_hash(rules: &[FinalRule], visibility: i16, obstacle: char) -> String {
+    let colors = robot_colors(rules, &[], obstacle);
+    let labels: Vec<char> = (0..colors.len())
+        .map(|label| char::from_digit(label as u32, 36).expect("fewer than 36 robot colors"))
+        .collect();
+    let canonical_form: Vec<CanonicalRule> = labels
+        .iter()
+        .cloned()
+        .permutations(labels.len())
+        .map(|relabeling| {
+            rules
+                .iter()
+                .map(|rule| {
+                    let view = apply_color_permutation_to_view(rule.view.clone(), &relabeling, &colors);
+                    let color = apply_color_permutation_to_color(rule.color, &relabeling, &colors);
+                    canonical_rule(&view, rule.direction, color)
+                })
+                .sorted()
+                .collect::<Vec<_>>()
+        })
+        .min()
+        .unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("visibility {}\n", visibility));
+    for (view, direction, color) in &canonical_form {
+        let cells = view.iter().map(|(c, x, y)| format!("{}{},{}", c, x, y)).join(" ");
+        hasher.update(format!("{} -> {} {}\n", cells, direction.as_web_algo_str(), color));
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn read_rules(path: &str) -> Option<(Vec<FinalRule>, i16)> {
+    match load_final_rules(Path::new(path)) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            None
+        }
+    }
+}
+
+/// Robot colors used by either algorithm, sorted; walls, empty cells, the obstacle
+/// and opacity marks are never permuted
+fn robot_colors(rules_a: &[FinalRule], rules_b: &[FinalRule], obstacle: char) -> Vec<char> {
+    rules_a
+        .iter()
+        .chain(rules_b)
+        .flat_map(|rule| rule.view.iter().map(|(c, _, _)| *c).chain([rule.color]))
+        .filter(|c| !matches!(c, '.' | 'W' | 'X' | '*') && *c != obstacle)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+fn rules_by_view(rules: &[FinalRule], permutation: &[char], colors: &[char]) -> RulesByView {
+    let mut by_view = RulesByView::new();
+    for (index, rule) in rules.iter().enumerate() {
+        let view = apply_color_permutation_to_view(rule.view.clone(), permutation, colors);
+        let color = apply_color_permutation_to_color(rule.color, permutation, colors);
+        let (view, direction, color) = canonical_rule(&view, rule.direction, color);
+        by_view.entry(view).or_default().push(((direction, color), index));
+    }
+    for actions in by_view.values_mut() {
+        actions.sort();
+    }
+    by_view
+}
+
+/// Views and actions without rule indices: equal for equivalent algorithms
+fn actions_only(by_view: &RulesByView) -> BTreeMap<&View, Vec<(Direction, char)>> {
+    by_view
+        .iter()
+        .map(|(view, actions)| (view, actions.iter().map(|(action, _)| *action).collect()))
+        .collect()
+}
+
+fn matched_rules(by_view_a: &RulesByView, by_view_b: &RulesByView) -> usize {
+    by_view_a
+        .iter()
+        .filter_map(|(view, actions_a)| Some((actions_a, by_view_b.get(view)?)))
+        .map(|(actions_a, actions_b)| {
+            actions_a
+                .iter()
+                .filter(|(action, _)| actions_b.iter().any(|(other, _)| other == action))
+                .count()
+        })
+        .sum()
+}
+
+fn print_rule_diff(
+    by_view_a: &RulesByView,
+    by_view_b: &RulesByView,
+    rules_a: &[FinalRule],
+    rules_b: &[FinalRule],
+    visibility: i16,
+) {
+    let mut only_a = Vec::new();
+    let mut only_b = Vec::new();
+    let mut changed = Vec::new();
+
+    for (view, actions_a) in by_view_a {
+        let Some(actions_b) = by_view_b.get(view) else {
+            only_a.extend(actions_a.iter().map(|(_, index)| *index));
+            continue;
+        };
+        let unmatched_a: Vec<usize> = actions_a
+            .iter()
+            .filter(|(action, _)| !actions_b.iter().any(|(other, _)| other == action))
+            .map(|(_, index)| *index)
+            .collect();
+        let unmatched_b: Vec<usize> = actions_b
+            .iter()
+            .filter(|(action, _)| !actions_a.iter().any(|(other, _)| other == action))
+            .map(|(_, index)| *index)
+            .collect();
+        if !unmatched_a.is_empty() || !unmatched_b.is_empty() {
+            changed.push((unmatched_a, unmatched_b));
+        }
+    }
+    for (view, actions_b) in by_view_b {
+        if !by_view_a.contains_key(view) {
+            only_b.extend(actions_b.iter().map(|(_, index)| *index));
+        }
+    }
+
+    println!("\nRules only in A ({}):", only_a.len());
+    for index in only_a {
+        print_rule("A", index, &rules_a[index], visibility);
+    }
+    println!("\nRules only in B ({}):", only_b.len());
+    for index in only_b {
+        print_rule("B", index, &rules_b[index], visibility);
+    }
+    println!("\nSame view, different action ({}):", changed.len());
+    for (indices_a, indices_b) in changed {
+        for index in indices_a {
+            print_rule("A", index, &rules_a[index], visibility);
+        }
+        for index in indices_b {
+            print_rule("B", index, &rules_b[index], visibility);
+        }
+        println!("  --");
+    }
+}
+
+/// Prints a rule as written in its file (colors not permuted)
+fn print_rule(side: &str, index: usize, rule: &FinalRule, visibility: i16) {
+    println!("  {} rule {}:", side, index);
+    let diagram =
+        generate_web_algo_string_rule(&rule.view, rule.direction, rule.color, visibility);
+    for line in diagram.lines().filter(|line| !line.trim().is_empty()) {
+        println!("    {}", line);
+    }
+}
+
+fn format_permutation(colors: &[char], permutation: &[char]) -> String {
+    if colors == permutation {
+        return "identity".to_string();
+    }
+    colors
+        .iter()
+        .zip(permutation)
+        .map(|(from, to)| format!("{}→{}", from, to))
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::logic::calculate_final_rules;
+
+    const FIXTURE: &str = include_str!("../data/fixtures/algo_1_act_37.web-algo");
+
+    #[test]
+    fn canonical_hash_ignores_renaming_a_color() {
+        let (final_rules, visibility) = calculate_final_rules(FIXTURE).unwrap();
+        // Letters outside any configured color set, in a different order
+        let renamed: Vec<FinalRule> = final_rules
+            .iter()
+            .map(|rule| FinalRule {
+                view: apply_color_permutation_to_view(rule.view.clone(), &['Z', 'A', 'M'], &['F', 'L', 'R']),
+                direction: rule.direction,
+                color: apply_color_permutation_to_color(rule.color, &['Z', 'A', 'M'], &['F', 'L', 'R']),
+            })
+            .collect();
+        let hash = canonical_hash(&final_rules, visibility, 'O');
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, canonical_hash(&renamed, visibility, 'O'));
+        assert_eq!(hash, canonical_hash(&renamed.iter().rev().cloned().collect::<Vec<_>>(), visibility, 'O'));
+
+        assert_ne!(hash, canonical_hash(&final_rules[1..], visibility, 'O'));
+        assert_ne!(hash, canonical_hash(&final_rules, visibility + 1, 'O'));
+    }
+}
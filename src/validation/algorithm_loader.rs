@@ -0,0 +1,431 @@
+// ============================================================================
+// ALGORITHM LOADER
+// ============================================================================
+//
+// Reads an algorithm in any of the three formats the project handles:
+// - `.web-algo`: the generator output, parsed by `extract_rules_from_content`
+// - `.yaml` / `.yml`: the `YamlAlgoGenerator` layout (`rules:` list of quoted
+//   views, each followed by `- (direction, color)`)
+// - `.json`: the `Vec<Algorithm>` seed format read by `AlgorithmManager`; the
+//   first algorithm is used, `*` cells are expanded to empty and wall
+//
+// Every format ends up as `FullRule`s with square views padded like the
+// `.web-algo` ones, so `convert_full_rules_to_final_rules` works unchanged.
+// The tools taking one algorithm file read it with `load_final_rules` and
+// name their outputs with `AlgorithmPath`.
+//
+// ============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::modules::{
+    algorithm::Algorithm, algorithm_manager::AlgorithmManager, direction::Direction,
+    final_rule::FinalRule, full_rule::FullRule,
+};
+use crate::validation::logic::{convert_full_rules_to_final_rules, extract_rules_from_content};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgorithmFormat {
+    WebAlgo,
+    Yaml,
+    Json,
+}
+
+impl AlgorithmFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "web-algo" => Some(AlgorithmFormat::WebAlgo),
+            "yaml" | "yml" => Some(AlgorithmFormat::Yaml),
+            "json" => Some(AlgorithmFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format from the file content, for callers that only have the text
+    pub fn detect(content: &str) -> Option<Self> {
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('[') || trimmed.starts_with('{') {
+            Some(AlgorithmFormat::Json)
+        } else if content.contains("****** RULES ******") {
+            Some(AlgorithmFormat::WebAlgo)
+        } else if content.lines().any(|line| line.trim() == "rules:") {
+            Some(AlgorithmFormat::Yaml)
+        } else {
+            None
+        }
+    }
+}
+
+/// True for `.web-algo` and `.yaml`/`.yml` files, and for `.json` files holding algorithms
+/// (other JSON files, such as traces or snapshots, may sit in the same folders)
+pub fn is_algorithm_file(path: &Path) -> bool {
+    match AlgorithmFormat::from_path(path) {
+        Some(AlgorithmFormat::Json) => fs::read_to_string(path)
+            .ok()
+            .is_some_and(|content| parse_json_algorithms(&content).is_ok()),
+        Some(_) => path.is_file(),
+        None => false,
+    }
+}
+
+/// Reads the rules of the algorithm file at `path` and its visibility range
+pub fn load_full_rules(path: &Path) -> Result<(Vec<FullRule>, i16), String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let format = AlgorithmFormat::from_path(path).or_else(|| AlgorithmFormat::detect(&content));
+    parse_full_rules(&content, format)
+}
+
+/// Reads the algorithm file at `path` as `FinalRule`s and its visibility range.
+/// The error names the file, ready to print.
+pub fn load_final_rules(path: &Path) -> Result<(Vec<FinalRule>, i16), String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let format = AlgorithmFormat::from_path(path).or_else(|| AlgorithmFormat::detect(&content));
+    let (rules, visibility) = parse_full_rules(&content, format)
+        .and_then(|(rules, visibility)| {
+            if rules.is_empty() {
+                Err("No rules found".to_string())
+            } else {
+                Ok((rules, visibility))
+            }
+        })
+        .map_err(|e| format!("Failed to read the rules of {}: {}", path.display(), e))?;
+    Ok((convert_full_rules_to_final_rules(&rules, visibility), visibility))
+}
+
+/// Names derived from an algorithm file path
+pub struct AlgorithmPath {
+    /// File name, shown in messages and reports
+    pub name: String,
+    /// File stem, naming the files written for the algorithm
+    pub stem: String,
+    /// Folder holding the file, where output folders are created
+    pub folder: PathBuf,
+}
+
+impl AlgorithmPath {
+    pub fn new(path: &Path) -> Self {
+        AlgorithmPath {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            stem: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "algo".to_string()),
+            folder: path.parent().unwrap_or_else(|| Path::new(".")).to_path_buf(),
+        }
+    }
+
+    /// `output_folder` next to the algorithm file
+    pub fn output_folder(&self, output_folder: &str) -> PathBuf {
+        self.folder.join(output_folder)
+    }
+}
+
+/// Parses `content` as `format`, or as the detected format when `None`
+pub fn parse_full_rules(
+    content: &str,
+    format: Option<AlgorithmFormat>,
+) -> Result<(Vec<FullRule>, i16), String> {
+    match format.or_else(|| AlgorithmFormat::detect(content)) {
+        Some(AlgorithmFormat::WebAlgo) => Ok(extract_rules_from_content(content)),
+        Some(AlgorithmFormat::Yaml) => parse_yaml_rules(content),
+        Some(AlgorithmFormat::Json) => parse_json_rules(content),
+        None => Err("Unknown algorithm format (expected .web-algo, YAML or JSON)".to_string()),
+    }
+}
+
+/// Reads the file at `path` as an `Algorithm` for `AlgorithmManager`, with unpadded
+/// diamond views like the JSON seeds
+pub fn load_algorithm(path: &Path) -> Result<Algorithm, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let format = AlgorithmFormat::from_path(path).or_else(|| AlgorithmFormat::detect(&content));
+    if format == Some(AlgorithmFormat::Json) {
+        return parse_json_algorithms(&content)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No algorithm in {}", path.display()));
+    }
+
+    let (rules, _) = parse_full_rules(&content, format)?;
+    let rules = rules
+        .into_iter()
+        .map(|rule| FullRule {
+            view: rule
+                .view
+                .iter()
+                .map(|row| row.iter().copied().filter(|c| *c != ' ').collect())
+                .collect(),
+            ..rule
+        })
+        .collect();
+    Ok(Algorithm {
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        rules,
+    })
+}
+
+fn parse_yaml_rules(content: &str) -> Result<(Vec<FullRule>, i16), String> {
+    let mut lines = content
+        .lines()
+        .skip_while(|line| line.trim() != "rules:")
+        .skip(1);
+    let mut rules = Vec::new();
+    let mut view_rows: Vec<String> = Vec::new();
+    let mut has_view = false;
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("- \"") {
+            // Quoted view, possibly spanning several lines
+            view_rows.clear();
+            let mut row = rest.to_string();
+            while !row.contains('"') {
+                view_rows.push(row);
+                row = lines
+                    .next()
+                    .ok_or("Unterminated view in YAML rules")?
+                    .to_string();
+            }
+            view_rows.push(row.split('"').next().unwrap_or_default().to_string());
+            has_view = true;
+        } else if let Some(action) = trimmed.strip_prefix("- (") {
+            let action = action.trim_end_matches(')');
+            let (direction, color) = action
+                .split_once(',')
+                .ok_or_else(|| format!("Expected '(direction, color)', found '{}'", trimmed))?;
+            if !has_view {
+                return Err(format!("Action '{}' has no view before it", trimmed));
+            }
+            has_view = false;
+            rules.push(FullRule {
+                view: pad_diamond_rows(&view_rows)?,
+                direction: Direction::from_str(direction),
+                color: color.trim().chars().next().unwrap_or(' '),
+            });
+        } else if trimmed.contains(':') {
+            // Next key after the rules list
+            break;
+        }
+    }
+
+    if rules.is_empty() {
+        return Err("No rules found in YAML 'rules:' section".to_string());
+    }
+    let visibility = common_visibility(&rules)?;
+    Ok((rules, visibility))
+}
+
+fn parse_json_algorithms(content: &str) -> Result<Vec<Algorithm>, String> {
+    serde_json::from_str::<Vec<Algorithm>>(content)
+        .or_else(|_| serde_json::from_str::<Algorithm>(content).map(|algorithm| vec![algorithm]))
+        .map_err(|e| format!("Not an algorithm JSON file: {}", e))
+}
+
+fn parse_json_rules(content: &str) -> Result<(Vec<FullRule>, i16), String> {
+    let algorithms = parse_json_algorithms(content)?;
+    let Some(algorithm) = algorithms.first() else {
+        return Err("No algorithm in JSON file".to_string());
+    };
+    if algorithms.len() > 1 {
+        eprintln!(
+            "[WARN] {} algorithms in JSON file, using the first ('{}')",
+            algorithms.len(),
+            algorithm.name
+        );
+    }
+
+    let mut rules = Vec::new();
+    for (index, rule) in algorithm.rules.iter().enumerate() {
+        if !AlgorithmManager::is_valid_full_view(&rule.view) {
+            return Err(format!("Rule {} of '{}' is not a diamond view", index, algorithm.name));
+        }
+        let rows: Vec<String> = rule.view.iter().map(|row| row.iter().collect()).collect();
+        let padded = FullRule {
+            view: pad_diamond_rows(&rows)?,
+            direction: rule.direction.clone(),
+            color: rule.color,
+        };
+        rules.extend(expand_stars(padded));
+    }
+
+    if rules.is_empty() {
+        return Err(format!("Algorithm '{}' has no rules", algorithm.name));
+    }
+    let visibility = common_visibility(&rules)?;
+    Ok((rules, visibility))
+}
+
+/// Re-centers diamond rows whatever their indentation: row `i` of a `2v + 1` row view
+/// has `|v - i|` leading spaces, as in `.web-algo` files
+fn pad_diamond_rows(rows: &[String]) -> Result<Vec<Vec<char>>, String> {
+    if rows.len() % 2 == 0 {
+        return Err(format!("View has {} rows, expected an odd count", rows.len()));
+    }
+    let visibility = rows.len() / 2;
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let cells: Vec<char> = row.chars().filter(|c| !c.is_whitespace()).collect();
+            let padding = visibility.abs_diff(i);
+            let expected = 2 * (visibility - padding) + 1;
+            if cells.len() != expected {
+                return Err(format!(
+                    "View row '{}' has {} cells, expected {}",
+                    row.trim(),
+                    cells.len(),
+                    expected
+                ));
+            }
+            Ok(std::iter::repeat(' ').take(padding).chain(cells).collect())
+        })
+        .collect()
+}
+
+/// One rule per combination of `*` cells set to empty or wall, like `AlgorithmManager::strip_stars`
+fn expand_stars(rule: FullRule) -> Vec<FullRule> {
+    let stars: Vec<(usize, usize)> = rule
+        .view
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c == '*')
+                .map(move |(x, _)| (y, x))
+        })
+        .collect();
+
+    (0..1usize << stars.len())
+        .map(|mask| {
+            let mut expanded = rule.clone();
+            for (i, &(y, x)) in stars.iter().enumerate() {
+                expanded.view[y][x] = if mask & (1 << i) == 0 { '.' } else { 'W' };
+            }
+            expanded
+        })
+        .collect()
+}
+
+fn common_visibility(rules: &[FullRule]) -> Result<i16, String> {
+    let visibility = (rules[0].view.len() / 2) as i16;
+    match rules
+        .iter()
+        .position(|rule| (rule.view.len() / 2) as i16 != visibility)
+    {
+        Some(index) => Err(format!(
+            "Rule {} has visibility {}, rule 0 has {}",
+            index,
+            rules[index].view.len() / 2,
+            visibility
+        )),
+        None => Ok(visibility),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../data/fixtures/algo_1_act_37.web-algo");
+
+    type RuleParts = (Vec<(char, i16, i16)>, Direction, char);
+
+    fn final_rules(content: &str) -> (Vec<RuleParts>, i16) {
+        let (rules, visibility) = parse_full_rules(content, None).unwrap();
+        let parts = convert_full_rules_to_final_rules(&rules, visibility)
+            .into_iter()
+            .map(|rule| (rule.view, rule.direction, rule.color))
+            .collect();
+        (parts, visibility)
+    }
+
+    /// Diamond rows of `rule` without the padding spaces
+    fn diamond_rows(rule: &FullRule) -> Vec<String> {
+        rule.view
+            .iter()
+            .map(|row| row.iter().filter(|c| **c != ' ').collect())
+            .collect()
+    }
+
+    /// The `rules:` section `YamlAlgoGenerator` writes, rows indented by the quote only
+    fn to_yaml(rules: &[FullRule]) -> String {
+        let mut yaml = "algorithm:\n  rules:\n".to_string();
+        for rule in rules {
+            yaml.push_str(&format!("  - \"{}\"\n", diamond_rows(rule).join("\n      ")));
+            yaml.push_str(&format!(
+                "  - ({}, {})\n",
+                rule.direction.as_web_algo_str(),
+                rule.color
+            ));
+        }
+        yaml.push_str("  grid_size: 20\n");
+        yaml
+    }
+
+    /// The `Vec<Algorithm>` seed layout, with unpadded diamond views
+    fn to_json(rules: &[FullRule]) -> String {
+        let rules = rules
+            .iter()
+            .map(|rule| FullRule {
+                view: diamond_rows(rule).iter().map(|row| row.chars().collect()).collect(),
+                ..rule.clone()
+            })
+            .collect();
+        serde_json::to_string(&vec![Algorithm {
+            name: "fixture".to_string(),
+            rules,
+        }])
+        .unwrap()
+    }
+
+    #[test]
+    fn yaml_and_json_equivalents_give_the_web_algo_rules() {
+        let (full_rules, _) = parse_full_rules(FIXTURE, None).unwrap();
+        let yaml = to_yaml(&full_rules);
+        let json = to_json(&full_rules);
+        assert_eq!(AlgorithmFormat::detect(FIXTURE), Some(AlgorithmFormat::WebAlgo));
+        assert_eq!(AlgorithmFormat::detect(&yaml), Some(AlgorithmFormat::Yaml));
+        assert_eq!(AlgorithmFormat::detect(&json), Some(AlgorithmFormat::Json));
+
+        let (web_algo_rules, visibility) = final_rules(FIXTURE);
+        assert_eq!(web_algo_rules.len(), 45);
+        assert_eq!(final_rules(&yaml), (web_algo_rules.clone(), visibility));
+        assert_eq!(final_rules(&json), (web_algo_rules, visibility));
+    }
+
+    #[test]
+    fn diamond_rows_are_recentered() {
+        let rows: Vec<String> = ["F", ".L.", " O"].iter().map(|row| row.to_string()).collect();
+        assert_eq!(
+            pad_diamond_rows(&rows).unwrap(),
+            vec![vec![' ', 'F'], vec!['.', 'L', '.'], vec![' ', 'O']]
+        );
+        assert!(pad_diamond_rows(&rows[..2]).is_err());
+        let short: Vec<String> = ["F", ".L", "O"].iter().map(|row| row.to_string()).collect();
+        assert!(pad_diamond_rows(&short).is_err());
+    }
+
+    #[test]
+    fn json_stars_expand_to_empty_and_wall() {
+        let json = r#"[{"name": "stars", "rules": [
+            {"view": [["*"], [".", "F", "*"], ["L"]], "direction": "Up", "color": "R"}
+        ]}]"#;
+        let (rules, visibility) = parse_full_rules(json, None).unwrap();
+        assert_eq!(visibility, 1);
+        let views: Vec<String> = rules.iter().map(|rule| diamond_rows(rule).concat()).collect();
+        assert_eq!(views, vec!["..F.L", "W.F.L", "..FWL", "W.FWL"]);
+    }
+}
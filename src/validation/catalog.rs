@@ -0,0 +1,353 @@
+// ============================================================================
+// ALGORITHM CATALOG
+// ============================================================================
+//
+// A file-based store of every validated algorithm across executions, under
+// `results/catalog/`: `index.json` keyed by `canonical_hash` (rules up to
+// rotation and color permutation) and a copy of the first file seen of each
+// class in `algorithms/`. `write_validation_outputs` records each validated
+// algorithm with its folder, status, config and classification families, and
+// reports which ones were already known. `--catalog list/show/search` read it.
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    methodology::configuration::{config_fingerprint, CONFIG},
+    modules::{
+        algorithm_status::AlgorithmStatus,
+        algorithm_validation_record::AlgorithmValidationRecord,
+        catalog::{Catalog, CatalogConfig, CatalogEntry, CatalogFamily, CatalogOccurrence},
+        classification::classification_result::ClassificationResult,
+    },
+    validation::{
+        algorithm_diff::canonical_hash,
+        algorithm_loader::{is_algorithm_file, load_final_rules},
+        logic::calculate_final_rules,
+    },
+};
+
+pub const CATALOG_FOLDER: &str = "results/catalog";
+
+fn index_path() -> PathBuf {
+    Path::new(CATALOG_FOLDER).join("index.json")
+}
+
+fn algorithms_path() -> PathBuf {
+    Path::new(CATALOG_FOLDER).join("algorithms")
+}
+
+/// An empty catalog when there is none yet
+pub fn load_catalog() -> Result<Catalog, String> {
+    match fs::read_to_string(index_path()) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", index_path().display(), e)),
+        Err(_) => Ok(Catalog::default()),
+    }
+}
+
+fn save_catalog(catalog: &Catalog) -> Result<(), String> {
+    fs::create_dir_all(algorithms_path())
+        .map_err(|e| format!("Failed to create {}: {}", algorithms_path().display(), e))?;
+    let json = serde_json::to_string_pretty(catalog).expect("Failed to serialize catalog");
+    fs::write(index_path(), json)
+        .map_err(|e| format!("Failed to write {}: {}", index_path().display(), e))
+}
+
+/// Adds the validated algorithms of `records` (`algo_files` holds their contents)
+/// to the catalog. Re-validating a folder replaces its earlier occurrences.
+pub fn record_in_catalog(
+    directory_path: &str,
+    algo_files: &[(String, String)],
+    records: &[AlgorithmValidationRecord],
+    classification: Option<&ClassificationResult>,
+) {
+    let mut catalog = match load_catalog() {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            eprintln!("❌ Catalog not updated: {}", e);
+            return;
+        }
+    };
+
+    let families = families_by_algorithm(classification);
+    let config = catalog_config();
+    let recorded_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let mut new_entries = 0;
+    let mut known = Vec::new();
+
+    for record in records.iter().filter(|record| {
+        matches!(
+            record.status,
+            AlgorithmStatus::Validated | AlgorithmStatus::ValidatedNotLd
+        )
+    }) {
+        let content = &algo_files[record.index].0;
+        let (final_rules, visibility) = match calculate_final_rules(content) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("❌ {} not recorded, unreadable: {}", record.file_name, e);
+                continue;
+            }
+        };
+        let hash = canonical_hash(&final_rules, visibility, CONFIG.obstacle);
+        let stem = Path::new(&record.file_name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| record.file_name.clone());
+
+        let occurrence = CatalogOccurrence {
+            execution: directory_path.to_string(),
+            file_name: record.file_name.clone(),
+            status: record.status,
+            recorded_at: recorded_at.clone(),
+            config: config.clone(),
+            families: families
+                .get(&format!("{}_{}", stem, record.status.simple_label()))
+                .cloned()
+                .unwrap_or_default(),
+        };
+
+        match catalog.entries.get_mut(&hash) {
+            Some(entry) => {
+                entry.occurrences.retain(|previous| {
+                    previous.execution != occurrence.execution
+                        || previous.file_name != occurrence.file_name
+                });
+                if let Some(first) = entry
+                    .occurrences
+                    .iter()
+                    .find(|previous| previous.execution != occurrence.execution)
+                {
+                    known.push(format!(
+                        "{} = {} in {}",
+                        record.file_name, first.file_name, first.execution
+                    ));
+                }
+                entry.occurrences.push(occurrence);
+            }
+            None => {
+                let file = format!("{}.web-algo", hash);
+                if let Err(e) = fs::create_dir_all(algorithms_path())
+                    .and_then(|_| fs::write(algorithms_path().join(&file), content))
+                {
+                    eprintln!("❌ Failed to store {} in the catalog: {}", record.file_name, e);
+                    continue;
+                }
+                catalog.entries.insert(
+                    hash.clone(),
+                    CatalogEntry {
+                        hash,
+                        rule_count: final_rules.len(),
+                        visibility,
+                        file,
+                        first_seen: recorded_at.clone(),
+                        occurrences: vec![occurrence],
+                    },
+                );
+                new_entries += 1;
+            }
+        }
+    }
+
+    if let Err(e) = save_catalog(&catalog) {
+        eprintln!("❌ Catalog not updated: {}", e);
+        return;
+    }
+    println!(
+        "Catalog: {} new algorithms, {} found in earlier executions ({} in '{}')",
+        new_entries,
+        known.len(),
+        catalog.entries.len(),
+        CATALOG_FOLDER
+    );
+    for line in known {
+        println!("  {}", line);
+    }
+}
+
+/// Family groups of each classified algorithm, by `AlgorithmExperiments::name`
+fn families_by_algorithm(
+    classification: Option<&ClassificationResult>,
+) -> HashMap<String, Vec<CatalogFamily>> {
+    let mut families: HashMap<String, Vec<CatalogFamily>> = HashMap::new();
+    for family in classification.iter().flat_map(|result| &result.families) {
+        for group in &family.groups {
+            for algorithm in &group.algorithms {
+                families
+                    .entry(algorithm.name.clone())
+                    .or_default()
+                    .push(CatalogFamily {
+                        family_number: family.family_number,
+                        title: family.title.clone(),
+                        group: group.hash.clone(),
+                    });
+            }
+        }
+    }
+    families
+}
+
+fn catalog_config() -> CatalogConfig {
+    CatalogConfig {
+        number_of_robots: CONFIG.number_of_robots,
+        number_of_colors: CONFIG.number_of_colors,
+        visibility_range: CONFIG.visibility_range,
+        all_color_letters: CONFIG.all_color_letters.clone(),
+        is_obstacle_opaque: CONFIG.is_obstacle_opaque,
+        fingerprint: config_fingerprint(),
+    }
+}
+
+/// Runs `--catalog list`, `--catalog show HASH` or `--catalog search TERM`
+pub fn run_catalog_command(args: &[String]) -> bool {
+    let catalog = match load_catalog() {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return false;
+        }
+    };
+
+    match args {
+        [command] if command == "list" => {
+            list_entries(catalog.entries.values());
+            true
+        }
+        [command, prefix] if command == "show" => {
+            let matches: Vec<&CatalogEntry> = catalog
+                .entries
+                .values()
+                .filter(|entry| entry.hash.starts_with(prefix.as_str()))
+                .collect();
+            match matches.as_slice() {
+                [entry] => {
+                    show_entry(entry);
+                    true
+                }
+                [] => {
+                    eprintln!("❌ No catalog entry starts with '{}'", prefix);
+                    false
+                }
+                _ => {
+                    eprintln!("❌ '{}' matches {} entries:", prefix, matches.len());
+                    list_entries(matches.into_iter());
+                    false
+                }
+            }
+        }
+        [command, term] if command == "search" => search(&catalog, term),
+        _ => {
+            eprintln!("❌ Expected list, show HASH or search TERM|ALGO");
+            false
+        }
+    }
+}
+
+/// An algorithm file is looked up by its canonical hash; anything else is matched
+/// against file names, folders, statuses and family titles
+fn search(catalog: &Catalog, term: &str) -> bool {
+    let path = Path::new(term);
+    if path.is_file() && is_algorithm_file(path) {
+        let (final_rules, visibility) = match load_final_rules(path) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                return false;
+            }
+        };
+        let hash = canonical_hash(&final_rules, visibility, CONFIG.obstacle);
+        return match catalog.entries.get(&hash) {
+            Some(entry) => {
+                println!("✅ Already in the catalog");
+                show_entry(entry);
+                true
+            }
+            None => {
+                println!("Not in the catalog (hash {})", hash);
+                false
+            }
+        };
+    }
+
+    let term = term.to_lowercase();
+    let matches: Vec<&CatalogEntry> = catalog
+        .entries
+        .values()
+        .filter(|entry| {
+            entry.hash.starts_with(&term)
+                || entry.occurrences.iter().any(|occurrence| {
+                    occurrence.file_name.to_lowercase().contains(&term)
+                        || occurrence.execution.to_lowercase().contains(&term)
+                        || occurrence.status.label().to_lowercase().contains(&term)
+                        || occurrence
+                            .families
+                            .iter()
+                            .any(|family| family.title.to_lowercase().contains(&term))
+                })
+        })
+        .collect();
+    let found = !matches.is_empty();
+    list_entries(matches.into_iter());
+    found
+}
+
+fn list_entries<'a>(entries: impl Iterator<Item = &'a CatalogEntry>) {
+    let mut count = 0;
+    println!(
+        "{:<16}  {:>5}  {:>3}  {:>5}  {:<19}  First seen as",
+        "Hash", "Rules", "Vis", "Seen", "First seen"
+    );
+    for entry in entries {
+        let first = entry.occurrences.first();
+        println!(
+            "{:<16}  {:>5}  {:>3}  {:>5}  {:<19}  {}",
+            entry.hash,
+            entry.rule_count,
+            entry.visibility,
+            entry.occurrences.len(),
+            entry.first_seen,
+            first
+                .map(|occurrence| format!("{}/{}", occurrence.execution, occurrence.file_name))
+                .unwrap_or_default()
+        );
+        count += 1;
+    }
+    println!("{} algorithms", count);
+}
+
+fn show_entry(entry: &CatalogEntry) {
+    println!("Algorithm {}", entry.hash);
+    println!("  Rules: {}, visibility {}", entry.rule_count, entry.visibility);
+    println!("  File: {}", algorithms_path().join(&entry.file).display());
+    println!("  First seen: {}", entry.first_seen);
+    println!("  Occurrences ({}):", entry.occurrences.len());
+    for occurrence in &entry.occurrences {
+        let config = &occurrence.config;
+        println!(
+            "    {}/{}  {}  {}",
+            occurrence.execution,
+            occurrence.file_name,
+            occurrence.status.label(),
+            occurrence.recorded_at
+        );
+        println!(
+            "      config {}: {} robots, {} colors {:?}, visibility {}, obstacle {}",
+            config.fingerprint,
+            config.number_of_robots,
+            config.number_of_colors,
+            config.all_color_letters,
+            config.visibility_range,
+            if config.is_obstacle_opaque { "opaque" } else { "transparent" }
+        );
+        for family in &occurrence.families {
+            println!(
+                "      family {:>2} {:<55} {}",
+                family.family_number, family.title, family.group
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::fs;
 use std::io::Write;
 
+use crate::modules::viewer_assets::utility_styles;
 use crate::validation::initial_config_generator::InitialConfig;
 
 /// Generate an HTML file that displays initial configurations
@@ -38,6 +39,7 @@ fn create_html_content(
 ) -> String {
     let initial_configs_json = format_collected_configs_json(initial_configurations);
 
+    let bundled_css = utility_styles();
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -45,7 +47,7 @@ fn create_html_content(
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Collected Configs Viewer</title>
-    <script src="https://cdn.tailwindcss.com"></script>
+    {bundled_css}
     <style>
         body {{
             font-family: "Inter", sans-serif;
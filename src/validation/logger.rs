@@ -1,13 +1,14 @@
 use crate::methodology::configuration::CONFIG;
 use crate::modules::algorithm_snapshot;
 use crate::modules::{
-    algorithm_snapshot::AlgorithmSnapshot, blocked_config_summary::BlockedConfigSummary,
+    algorithm_snapshot::AlgorithmSnapshot, algorithm_validation_record::AlgorithmValidationRecord,
+    blocked_config_summary::BlockedConfigSummary, experiment_row::ExperimentRow,
     grid_experiment::GridExperiment, validation_config::ValidationConfig,
 };
-use std::io::{self, BufWriter, Result, Write};
+use std::io::{self, BufRead, BufWriter, Result, Write};
 use std::{
     fs::{self, File},
-    path::Path,
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
 
@@ -418,7 +419,7 @@ fn create_inverse_blocked_summaries_log(
 
 pub fn write_algorithm_summary_log(
     directory_path: &str,
-    results: &Arc<Mutex<Vec<String>>>,
+    results: &[String],
     snapshot: &AlgorithmSnapshot,
     total_count: usize,
 ) -> io::Result<()> {
@@ -430,6 +431,7 @@ pub fn write_algorithm_summary_log(
     let blocked_count = snapshot.blocked.len();
     let cyclic_count = snapshot.cyclic.len();
     let timeout_count = snapshot.timeout.len();
+    let unreadable_count = snapshot.unreadable.len();
     writeln!(log_file, "VALIDATION SUMMARY (Algorithm-Level Status)")?;
     writeln!(
         log_file,
@@ -465,14 +467,14 @@ pub fn write_algorithm_summary_log(
     )?;
     write_names(&mut log_file, "      ↳", &snapshot.timeout)?;
 
-    writeln!(log_file)?;
+    writeln!(
+        log_file,
+        "— Unreadable:         {}/{}",
+        unreadable_count, total_count
+    )?;
+    write_names(&mut log_file, "      ↳", &snapshot.unreadable)?;
 
-    let results = results.lock().map_err(|_| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            "Failed to acquire lock on results vector",
-        )
-    })?;
+    writeln!(log_file)?;
 
     for result in results.iter() {
         writeln!(log_file, "{}", result)?;
@@ -481,6 +483,74 @@ pub fn write_algorithm_summary_log(
     Ok(())
 }
 
+const RESULTS_JSONL: &str = "results.jsonl";
+const RESULTS_CSV: &str = "results.csv";
+
+/// `results.jsonl` and `results.csv`: one row per algorithm × experiment.
+/// `process_algo` appends the rows of each algorithm as soon as it finishes, so
+/// algorithms appear in completion order (sort on `algorithm_index` if needed).
+pub struct ResultsTables {
+    jsonl: BufWriter<File>,
+    csv: BufWriter<File>,
+    row_count: usize,
+}
+
+impl ResultsTables {
+    pub fn create(directory_path: &str) -> Self {
+        let directory = Path::new(directory_path);
+        let jsonl = File::create(directory.join(RESULTS_JSONL)).expect("Unable to create results.jsonl");
+        let csv = File::create(directory.join(RESULTS_CSV)).expect("Unable to create results.csv");
+        let mut tables = Self {
+            jsonl: BufWriter::new(jsonl),
+            csv: BufWriter::new(csv),
+            row_count: 0,
+        };
+        writeln!(tables.csv, "{}", ExperimentRow::CSV_HEADER).expect("Failed to write results.csv");
+        tables
+    }
+
+    pub fn write_rows(&mut self, rows: &[ExperimentRow]) {
+        for row in rows {
+            let line = serde_json::to_string(row).expect("Failed to serialize experiment row");
+            writeln!(self.jsonl, "{}", line).expect("Failed to write results.jsonl");
+            writeln!(self.csv, "{}", row.to_csv_line()).expect("Failed to write results.csv");
+        }
+        self.row_count += rows.len();
+    }
+
+    pub fn finish(mut self, directory_path: &str) {
+        self.jsonl.flush().expect("Failed to write results.jsonl");
+        self.csv.flush().expect("Failed to write results.csv");
+        println!(
+            "{} experiment rows written to '{}/{}' and '{}/{}'",
+            self.row_count, directory_path, RESULTS_JSONL, directory_path, RESULTS_CSV
+        );
+    }
+}
+
+/// Concatenates the results tables of `shard_paths`, in the given order, into
+/// `directory_path` (the CSV header is kept once)
+pub fn merge_results_tables(directory_path: &str, shard_paths: &[PathBuf]) {
+    let directory = Path::new(directory_path);
+    let mut jsonl = BufWriter::new(File::create(directory.join(RESULTS_JSONL)).expect("Unable to create results.jsonl"));
+    let mut csv = BufWriter::new(File::create(directory.join(RESULTS_CSV)).expect("Unable to create results.csv"));
+    writeln!(csv, "{}", ExperimentRow::CSV_HEADER).expect("Failed to write results.csv");
+
+    for shard_path in shard_paths {
+        if let Ok(file) = File::open(shard_path.join(RESULTS_JSONL)) {
+            io::copy(&mut io::BufReader::new(file), &mut jsonl).expect("Failed to write results.jsonl");
+        }
+        if let Ok(file) = File::open(shard_path.join(RESULTS_CSV)) {
+            for line in io::BufReader::new(file).lines().skip(1) {
+                let line = line.expect("Failed to read shard results.csv");
+                writeln!(csv, "{}", line).expect("Failed to write results.csv");
+            }
+        }
+    }
+    jsonl.flush().expect("Failed to write results.jsonl");
+    csv.flush().expect("Failed to write results.csv");
+}
+
 fn write_names(writer: &mut File, prefix: &str, entries: &[String]) -> io::Result<()> {
     if entries.is_empty() {
         return Ok(());
@@ -5,7 +5,7 @@
 // This module validates robot algorithms by testing them on different grid configurations.
 //
 // **How it works:**
-// 1. Load algorithm files (.web-algo format)
+// 1. Load algorithm files (.web-algo, YAML or JSON, see `algorithm_loader`)
 // 2. Generate test configurations (different grid sizes, robot positions)
 // 3. Run each algorithm on each configuration
 // 4. Determine if the algorithm works correctly
@@ -29,7 +29,7 @@ use rayon::{prelude::*, vec};
 use std::f32::consts::E;
 // Standard library
 use std::fs::{self, File};
-use std::io::{self, BufWriter, Result, Write};
+use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::process::{exit, id};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -37,7 +37,7 @@ use std::sync::{Arc, Mutex};
 
 use crate::classification::logic::classify;
 use crate::methodology::configuration::CONFIG;
-use crate::methodology::globals::get_execution_root_str;
+use crate::methodology::globals::{get_execution_root_str, try_get_execution_root};
 use crate::modules::algorithm_experiments_modules::algo_info_by_robot_colors::{
     self, AlgoInfoByRobotColors,
 };
@@ -51,6 +51,11 @@ use crate::modules::algorithm_experiments_modules::robot_criteria::{self, RobotC
 use crate::modules::algorithm_snapshot::AlgorithmSnapshot;
 use crate::modules::algorithm_stats::AlgorithmStats;
 use crate::modules::algorithm_status::AlgorithmStatus;
+use crate::modules::algorithm_validation_record::AlgorithmValidationRecord;
+use crate::modules::execution_logger::start_stage;
+use crate::modules::experiment_row::ExperimentRow;
+use crate::modules::rule_coverage::RuleCoverage;
+use crate::methodology::simulator::generate_web_algo_string_rule;
 use crate::modules::color::get_colors;
 use crate::modules::config_stats::ConfigStats;
 use crate::modules::execution_status::ExecutionStatus;
@@ -60,6 +65,7 @@ use crate::modules::grid_experiment::GridExperiment;
 use crate::modules::grid_size_generator::generate_grid_definitions;
 use crate::modules::init_config::InitConfig;
 use crate::modules::position::{self, Position};
+use crate::modules::shard_spec::ShardSpec;
 use crate::modules::{grid, rule};
 // Internal modules
 
@@ -67,17 +73,22 @@ use crate::methodology::view::distribute_abstract_positions;
 use crate::modules::{
     blocked_config_summary::BlockedConfigSummary,
     direction::{calculate_movement, rotate_direction, Direction},
+    execution_trace::{RobotStepTrace, RuleMatch, TraceRound},
     final_rule::FinalRule,
     full_rule::FullRule,
     validation_config::ValidationConfig,
+    validation_policy::ValidationPolicy,
     validation_progress_bars::{create_progress_bars, finish_progress_bars, start_status_updater},
     view::{are_equivalent, rotate_view},
 };
+use crate::validation::algorithm_loader::{is_algorithm_file, parse_full_rules};
+use crate::validation::catalog::record_in_catalog;
 use crate::validation::initial_config_generator::generate_initial_configs;
 use crate::validation::initial_config_viewer::initial_config_viewer_html;
+use crate::validation::rule_coverage_report::{write_rule_coverage, RuleCoverageLog};
 use crate::validation::logger::{
-    create_blocked_summaries_log, log_all_possible_configurations,
-    write_algorithm_summary_log, write_validation_summary_log,
+    create_blocked_summaries_log, log_all_possible_configurations, write_algorithm_summary_log,
+    write_validation_summary_log, ResultsTables,
 };
 
 /// Main validation entry point
@@ -110,12 +121,15 @@ pub fn create_validation_configs(execution_root: &str) -> Vec<GridExperiment> {
         format!("{}/initial_configs_viewer.html", execution_root).as_str(),
     )
     .expect("Failed to generate viewer HTML");
-    let basic_grid_len = ((CONFIG.number_of_robots as i16 + 1) * CONFIG.visibility_range) * 2 + 1;
     let grid_definitions: Vec<(i16, i16, Vec<(i16, i16)>)> =
-        generate_grid_definitions(basic_grid_len);
+        generate_grid_definitions(basic_grid_len());
 
     // Generate simple text format for grid definitions
-    let grid_definitions_path = format!("{}/grid_definitions.txt", get_execution_root_str());
+    // (direct validation runs have no execution root, so fall back to the validated folder)
+    let grid_definitions_root = try_get_execution_root()
+        .map(|root| root.to_string_lossy().into_owned())
+        .unwrap_or_else(|| execution_root.to_string());
+    let grid_definitions_path = format!("{}/grid_definitions.txt", grid_definitions_root);
     let mut grid_text = String::new();
 
     for (row, col, obstacles) in &grid_definitions {
@@ -141,6 +155,20 @@ pub fn create_validation_configs(execution_root: &str) -> Vec<GridExperiment> {
     )
 }
 
+/// The `GridExperiment`s of `create_validation_configs`, without writing the viewer
+/// and grid definitions files
+pub fn build_grid_experiments() -> Vec<GridExperiment> {
+    generate_all_configs_with_positions_indices(
+        CONFIG.initial_configurations.clone(),
+        generate_grid_definitions(basic_grid_len()),
+    )
+}
+
+/// Smallest side of the validation grids
+pub fn basic_grid_len() -> i16 {
+    ((CONFIG.number_of_robots as i16 + 1) * CONFIG.visibility_range) * 2 + 1
+}
+
 fn parse_activation_level(folder_name: &str) -> Option<i16> {
     folder_name
         .split('_')
@@ -175,13 +203,13 @@ fn validate_with_folder_hierarchy(base_path: &str, list_of_grid_experiment: &[Gr
                     folders.push((activation, path));
                 }
             }
-        } else if path.extension().map_or(false, |ext| ext == "web-algo") {
+        } else if is_algorithm_file(&path) {
             has_files = true;
         }
     }
 
     if has_files && folders.is_empty() {
-        println!("Found .web-algo files in base directory. Validating...");
+        println!("Found algorithm files in base directory. Validating...");
         validate_directory(base_path, list_of_grid_experiment);
         return;
     }
@@ -249,8 +277,9 @@ fn validate_directory(
     log_all_possible_configurations(directory_path, list_of_grid_experiment)
         .expect("Failed to log configurations in folder");
 
+    let stage = start_stage("validate_directory");
     let algo_stats = Arc::new(AlgorithmStats::default());
-    let results = Arc::new(Mutex::new(Vec::new()));
+    let results = Arc::new(Mutex::new(Vec::<AlgorithmValidationRecord>::new()));
     let blocked_summaries = Arc::new(Mutex::new(Vec::<BlockedConfigSummary>::new()));
 
     run_all_algos(
@@ -260,39 +289,98 @@ fn validate_directory(
         &results,
         &blocked_summaries,
         directory_path,
+        None,
+    );
+
+    let algo_snapshot = write_validation_outputs(
+        directory_path,
+        &algo_files,
+        list_of_grid_experiment,
+        &algo_stats,
+        &results,
+        &blocked_summaries,
     );
+    stage.finish(&[
+        ("algorithms", algo_files.len()),
+        ("experiments", list_of_grid_experiment.len()),
+        ("validated_ld", algo_snapshot.validated_ld.len()),
+        ("validated_not_ld", algo_snapshot.validated_not_ld.len()),
+        ("blocked", algo_snapshot.blocked.len()),
+        ("cyclic", algo_snapshot.cyclic.len()),
+        ("timeout", algo_snapshot.timeout.len()),
+        ("unreadable", algo_snapshot.unreadable.len()),
+    ]);
+    algo_snapshot
+}
 
+/// Writes the folder-level logs and runs classification on `_details`.
+/// Shared by single-process validation and `merge_shards` so both produce the same files.
+pub fn write_validation_outputs(
+    directory_path: &str,
+    algo_files: &[(String, String)],
+    list_of_grid_experiment: &[GridExperiment],
+    algo_stats: &Arc<AlgorithmStats>,
+    results: &Arc<Mutex<Vec<AlgorithmValidationRecord>>>,
+    blocked_summaries: &Arc<Mutex<Vec<BlockedConfigSummary>>>,
+) -> AlgorithmSnapshot {
     let algo_snapshot = algo_stats.snapshot();
     let validated_ld_algo = algo_snapshot.validated_ld.len();
     let validated_not_ld_algo = algo_snapshot.validated_not_ld.len();
     let blocked_algo = algo_snapshot.blocked.len();
     let cyclic_algo = algo_snapshot.cyclic.len();
     let timeout_algo = algo_snapshot.timeout.len();
+    let unreadable_algo = algo_snapshot.unreadable.len();
     let total_count: usize = algo_files.len();
 
-    write_algorithm_summary_log(directory_path, &results, &algo_snapshot, total_count);
+    // Records arrive in completion order; sort by file index so the log is reproducible
+    let algo_summaries: Vec<String> = {
+        let mut records = results.lock().unwrap();
+        records.sort_by_key(|record| record.index);
+        records
+            .iter()
+            .map(|record| {
+                let metrics = AlgorithmMetrics::from_outcomes(&record.config_outcomes);
+                format_algorithm_result(
+                    record.index,
+                    &record.file_name,
+                    record.status,
+                    &metrics,
+                    &record.config_outcomes,
+                )
+            })
+            .collect()
+    };
+
+    write_algorithm_summary_log(directory_path, &algo_summaries, &algo_snapshot, total_count);
+    write_rule_coverage(directory_path);
 
     println!(
         "Validation results written to '{}'",
         format!("{}/_validation_results.log", directory_path)
     );
     println!(
-        "Summary: {} validated (LD), {} validated (NOT-LD), {} blocked, {} cyclic, {} timeout",
-        validated_ld_algo, validated_not_ld_algo, blocked_algo, cyclic_algo, timeout_algo
+        "Summary: {} validated (LD), {} validated (NOT-LD), {} blocked, {} cyclic, {} timeout, {} unreadable",
+        validated_ld_algo, validated_not_ld_algo, blocked_algo, cyclic_algo, timeout_algo, unreadable_algo
     );
 
     create_blocked_summaries_log(
         directory_path,
-        &blocked_summaries,
-        &algo_files,
+        blocked_summaries,
+        algo_files,
         list_of_grid_experiment,
     );
 
     // Run classification after validation
-    classify(
+    let classification = classify(
         format!("{}/_details", directory_path).as_str(),
         directory_path,
     );
+    record_in_catalog(
+        directory_path,
+        algo_files,
+        &results.lock().unwrap(),
+        classification.as_ref(),
+    );
 
     algo_snapshot
 }
@@ -301,16 +389,24 @@ fn validate_directory(
 // PARALLEL VALIDATION EXECUTION
 // ============================================================================
 
-fn run_all_algos(
+/// Validates every algorithm file, or only the files selected by `shard`.
+/// Indices passed to `process_algo` always refer to the full `algo_files` list.
+pub fn run_all_algos(
     algo_files: &[(String, String)],
     list_of_grid_experiment: &[GridExperiment],
     algo_stats: &Arc<AlgorithmStats>,
-    results: &Arc<Mutex<Vec<String>>>,
+    results: &Arc<Mutex<Vec<AlgorithmValidationRecord>>>,
     blocked_summaries: &Arc<Mutex<Vec<BlockedConfigSummary>>>,
     base_path: &str,
+    shard: Option<ShardSpec>,
 ) {
+    let selected_indices: Vec<usize> = (0..algo_files.len())
+        .filter(|index| shard.map_or(true, |spec| spec.contains(*index)))
+        .collect();
+
     // Create progress bars
-    let progress_bars = create_progress_bars(algo_files.len(), list_of_grid_experiment.len());
+    let progress_bars =
+        create_progress_bars(selected_indices.len(), list_of_grid_experiment.len());
 
     // Start status updater thread with ALGORITHM-LEVEL counters (not config-level)
     // We want to see algorithm progress during execution, not individual config counts
@@ -325,24 +421,28 @@ fn run_all_algos(
     // Process algorithms in parallel
     let pb_algo = progress_bars.algo.clone();
     let pb_config = progress_bars.config.clone();
-
-    algo_files
-        .par_iter()
-        .enumerate()
-        .for_each(|(index, (algo, file_name))| {
-            process_algo(
-                index,
-                algo,
-                file_name,
-                list_of_grid_experiment,
-                algo_stats,
-                results,
-                blocked_summaries,
-                &pb_config,
-                base_path,
-            );
-            pb_algo.inc(1);
-        });
+    let results_tables = Mutex::new(ResultsTables::create(base_path));
+    let rule_coverage_log = Mutex::new(RuleCoverageLog::create(base_path));
+
+    selected_indices.par_iter().for_each(|&index| {
+        let (algo, file_name) = &algo_files[index];
+        process_algo(
+            index,
+            algo,
+            file_name,
+            list_of_grid_experiment,
+            algo_stats,
+            results,
+            &results_tables,
+            &rule_coverage_log,
+            blocked_summaries,
+            &pb_config,
+            base_path,
+        );
+        pb_algo.inc(1);
+    });
+    results_tables.into_inner().unwrap().finish(base_path);
+    rule_coverage_log.into_inner().unwrap().finish();
 
     // Get final algorithm-level counts
     let final_snapshot: AlgorithmSnapshot = algo_stats.snapshot();
@@ -355,7 +455,7 @@ fn run_all_algos(
         final_snapshot.blocked.len(),
         final_snapshot.cyclic.len(),
         final_snapshot.timeout.len(),
-        algo_files.len(),
+        selected_indices.len(),
         base_path,
     );
 }
@@ -370,24 +470,52 @@ fn process_algo(
     file_name: &str,
     list_of_grid_experiment: &[GridExperiment],
     algo_stats: &Arc<AlgorithmStats>,
-    results: &Arc<Mutex<Vec<String>>>,
+    results: &Arc<Mutex<Vec<AlgorithmValidationRecord>>>,
+    results_tables: &Mutex<ResultsTables>,
+    rule_coverage_log: &Mutex<RuleCoverageLog>,
     blocked_summaries: &Arc<Mutex<Vec<BlockedConfigSummary>>>,
     pb_config: &ProgressBar,
     base_path: &str,
 ) {
     let config_stats = Arc::new(ConfigStats::default());
 
-    let (final_rules, visibility) = calculate_final_rules(algo);
+    let (final_rules, visibility) = match calculate_final_rules(algo) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            // Reported on its own: simulating no rules would count it as blocked
+            eprintln!("❌ {} is unreadable: {}", file_name, e);
+            update_algorithm_counters(AlgorithmStatus::Unreadable, algo_stats, file_name);
+            pb_config.inc(list_of_grid_experiment.len() as u64);
+            results.lock().unwrap().push(AlgorithmValidationRecord {
+                index,
+                file_name: file_name.to_string(),
+                status: AlgorithmStatus::Unreadable,
+                config_outcomes: Vec::new(),
+            });
+            return;
+        }
+    };
 
-    let sim_results: Vec<(ExecutionStatus, ExperimentResult)> = list_of_grid_experiment
-        .par_iter()
-        .enumerate()
-        .map(|(i, grid_experiment)| {
-            let result = simulate_exploration(i, grid_experiment, &final_rules, visibility);
-            pb_config.inc(1);
-            result
-        })
-        .collect();
+    let sim_results: Vec<(ExecutionStatus, ExperimentResult)> =
+        if CONFIG.validation_policy == ValidationPolicy::RunAll {
+            list_of_grid_experiment
+                .par_iter()
+                .enumerate()
+                .map(|(i, grid_experiment)| {
+                    let result = simulate_exploration(i, grid_experiment, &final_rules, visibility);
+                    pb_config.inc(1);
+                    result
+                })
+                .collect()
+        } else {
+            simulate_explorations_with_early_exit(
+                list_of_grid_experiment,
+                &final_rules,
+                visibility,
+                CONFIG.validation_policy,
+                pb_config,
+            )
+        };
 
     // Separate into two vectors
     let (statuses, experiment_results): (Vec<_>, Vec<_>) = sim_results.into_iter().unzip();
@@ -411,12 +539,45 @@ fn process_algo(
 
     // Update counters based on final status
     update_algorithm_counters(status, algo_stats, file_name);
-    let algo_summary = format_algorithm_result(index, file_name, status, &metrics, &statuses);
 
     //TODO:
     let (algo_infos_by_robot_colors, total_activation) =
         calculate_algo_infos_by_robot_colors(&final_rules);
 
+    let experiment_rows: Vec<ExperimentRow> = statuses
+        .iter()
+        .zip(experiment_results.iter())
+        .map(|(experiment_status, experiment)| {
+            ExperimentRow::new(
+                index,
+                file_name,
+                status,
+                final_rules.len(),
+                visibility,
+                experiment_status.clone(),
+                experiment,
+            )
+        })
+        .collect();
+    results_tables.lock().unwrap().write_rows(&experiment_rows);
+
+    let rule_coverage = RuleCoverage::new(
+        index,
+        file_name,
+        status,
+        final_rules
+            .iter()
+            .map(|rule| {
+                generate_web_algo_string_rule(&rule.view, rule.direction.clone(), rule.color, visibility)
+                    .trim_start_matches(" \n")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect(),
+        &experiment_results,
+    );
+    rule_coverage_log.lock().unwrap().write(&rule_coverage);
+
     if status == AlgorithmStatus::Validated {
         save_algorithm_experiments(
             &file_name,
@@ -427,7 +588,12 @@ fn process_algo(
             algo_infos_by_robot_colors,
         );
     }
-    results.lock().unwrap().push(algo_summary);
+    results.lock().unwrap().push(AlgorithmValidationRecord {
+        index,
+        file_name: file_name.to_string(),
+        status,
+        config_outcomes: statuses,
+    });
 }
 
 pub fn calculate_algo_infos_by_robot_colors(
@@ -490,17 +656,29 @@ fn claculate_activations_by_color(
     )
 }
 
-fn simulate_exploration(
+pub fn simulate_exploration(
     experiment_id: usize,
     grid_experiment: &GridExperiment,
     final_rules: &[FinalRule],
     visibility: i16,
+) -> (ExecutionStatus, ExperimentResult) {
+    simulate_exploration_traced(experiment_id, grid_experiment, final_rules, visibility, None)
+}
+
+/// Same as `simulate_exploration`, also recording every round into `trace` when given
+pub fn simulate_exploration_traced(
+    experiment_id: usize,
+    grid_experiment: &GridExperiment,
+    final_rules: &[FinalRule],
+    visibility: i16,
+    mut trace: Option<&mut Vec<TraceRound>>,
 ) -> (ExecutionStatus, ExperimentResult) {
     // Initialize robot metrics
     let mut color_activations = initialize_counters(&grid_experiment);
     let mut movement_activations = initialize_counters(&grid_experiment);
     let mut rules_count = initialize_counters(&grid_experiment);
     let mut idle_rules_count = initialize_counters(&grid_experiment);
+    let mut rules_fired_per_step: Vec<Vec<usize>> = vec![]; // Rule indices fired at each step
     let mut activations_per_step: Vec<usize> = vec![]; // Track activations at each step
 
     let mut robots_history: Vec<Vec<(char, i16, i16)>> =
@@ -522,7 +700,9 @@ fn simulate_exploration(
             &mut movement_activations,
             &mut rules_count,
             &mut idle_rules_count,
+            &mut rules_fired_per_step,
             &mut activations_per_step,
+            trace.as_deref_mut(),
         ) {
             let experiment_result = calculate_experiment_result(
                 experiment_id,
@@ -532,6 +712,8 @@ fn simulate_exploration(
                 &movement_activations,
                 &rules_count,
                 &idle_rules_count,
+                final_rules.len(),
+                &rules_fired_per_step,
                 steps,
                 0,
                 &[],
@@ -568,6 +750,8 @@ fn simulate_exploration(
                 &movement_activations,
                 &rules_count,
                 &idle_rules_count,
+                final_rules.len(),
+                &rules_fired_per_step,
                 steps,
                 0,
                 &[],
@@ -584,6 +768,8 @@ fn simulate_exploration(
         &movement_activations,
         &rules_count,
         &idle_rules_count,
+        final_rules.len(),
+        &rules_fired_per_step,
         steps,
         cycle_len,
         &activations_per_step,
@@ -596,6 +782,65 @@ fn simulate_exploration(
     }
 }
 
+/// Runs the experiments in order and marks the rest as `Skipped` once `policy` says the
+/// algorithm status is decided. Sequential so the skipped set is the same on every run;
+/// algorithms are still processed in parallel by `run_all_algos`.
+fn simulate_explorations_with_early_exit(
+    list_of_grid_experiment: &[GridExperiment],
+    final_rules: &[FinalRule],
+    visibility: i16,
+    policy: ValidationPolicy,
+    pb_config: &ProgressBar,
+) -> Vec<(ExecutionStatus, ExperimentResult)> {
+    let mut sim_results: Vec<(ExecutionStatus, ExperimentResult)> =
+        Vec::with_capacity(list_of_grid_experiment.len());
+    let mut statuses: Vec<ExecutionStatus> = Vec::with_capacity(list_of_grid_experiment.len());
+    let mut stopped = false;
+
+    for (i, grid_experiment) in list_of_grid_experiment.iter().enumerate() {
+        if stopped {
+            sim_results.push((
+                ExecutionStatus::Skipped,
+                skipped_experiment_result(i, grid_experiment),
+            ));
+            pb_config.inc(1);
+            continue;
+        }
+
+        let (status, experiment_result) =
+            simulate_exploration(i, grid_experiment, final_rules, visibility);
+        statuses.push(status.clone());
+        let metrics = AlgorithmMetrics::from_outcomes(&statuses);
+        stopped = policy.should_stop(
+            &status,
+            grid_experiment.init_config.is_essential,
+            &metrics,
+        );
+        sim_results.push((status, experiment_result));
+        pb_config.inc(1);
+    }
+
+    sim_results
+}
+
+fn skipped_experiment_result(
+    experiment_id: usize,
+    grid_experiment: &GridExperiment,
+) -> ExperimentResult {
+    ExperimentResult {
+        experiment_id,
+        grid_experiment: grid_experiment.clone(),
+        status: ExecutionStatus::Skipped,
+        steps_taken: 0,
+        cycle_len: 0,
+        total_activation_in_cycle: 0,
+        total_activation: 0,
+        robots_metrics: vec![],
+        rule_fires: vec![],
+        rule_fires_in_cycle: vec![],
+    }
+}
+
 fn initialize_counters(grid_experiment: &GridExperiment) -> Vec<usize> {
     grid_experiment
         .init_config
@@ -612,6 +857,8 @@ fn calculate_experiment_result(
     movement_activations: &Vec<usize>,
     rule_count: &Vec<usize>,
     idle_rules_count: &Vec<usize>,
+    final_rule_count: usize,
+    rules_fired_per_step: &[Vec<usize>],
     steps: usize,
     cycle_len: usize,
     activations_per_step: &[usize],
@@ -655,6 +902,23 @@ fn calculate_experiment_result(
 
     let total_activation: usize = activations_per_step.iter().sum();
 
+    // Same window for rule fires: the last cycle_len steps are the cycle
+    let mut rule_fires = vec![0; final_rule_count];
+    let mut rule_fires_in_cycle = vec![0; final_rule_count];
+    let cycle_start = if cycle_len > 0 {
+        rules_fired_per_step.len().saturating_sub(cycle_len)
+    } else {
+        rules_fired_per_step.len()
+    };
+    for (step, fired) in rules_fired_per_step.iter().enumerate() {
+        for &rule_index in fired {
+            rule_fires[rule_index] += 1;
+            if step >= cycle_start {
+                rule_fires_in_cycle[rule_index] += 1;
+            }
+        }
+    }
+
     ExperimentResult {
         experiment_id,
         grid_experiment: grid_experiment.clone(),
@@ -664,6 +928,8 @@ fn calculate_experiment_result(
         total_activation_in_cycle,
         total_activation,
         robots_metrics: robots_metrics.clone(),
+        rule_fires,
+        rule_fires_in_cycle,
     }
 }
 
@@ -689,7 +955,7 @@ fn config_status_label(status: &ExecutionStatus) -> &'static str {
 }
 
 /// Determines algorithm status using priority rules
-fn determine_algorithm_status(metrics: &AlgorithmMetrics) -> AlgorithmStatus {
+pub fn determine_algorithm_status(metrics: &AlgorithmMetrics) -> AlgorithmStatus {
     if metrics.timeout > 0 {
         AlgorithmStatus::Timeout
     } else if metrics.cyclic > 0 {
@@ -714,15 +980,20 @@ fn update_algorithm_counters(
     algo_stats.insert(status, algo_name);
 }
 /// Formats the algorithm result for display
-fn format_algorithm_result(
+pub fn format_algorithm_result(
     index: usize,
     file_name: &str,
     algo_status: AlgorithmStatus,
     metrics: &AlgorithmMetrics,
     config_outcomes: &[ExecutionStatus],
 ) -> String {
+    let skipped = if metrics.skipped > 0 {
+        format!("  ⏭️{}", metrics.skipped)
+    } else {
+        String::new()
+    };
     let mut result = format!(
-        "{}) {} ................... {} (✅{}  ⚠️{}  ❌{}  🔄{}  ⏱️{})\n",
+        "{}) {} ................... {} (✅{}  ⚠️{}  ❌{}  🔄{}  ⏱️{}{})\n",
         index,
         file_name,
         algo_status.label(),
@@ -730,7 +1001,8 @@ fn format_algorithm_result(
         metrics.validated_not_ld,
         metrics.blocked,
         metrics.cyclic,
-        metrics.timeout
+        metrics.timeout,
+        skipped
     );
 
     // Add details for each configuration (optional - can be commented out for cleaner output)
@@ -805,11 +1077,15 @@ fn simulate_step(
     movement_activations: &mut Vec<usize>,
     rules_count: &mut Vec<usize>,
     idle_rules_count: &mut Vec<usize>,
+    rules_fired_per_step: &mut Vec<Vec<usize>>,
     activations_per_step: &mut Vec<usize>,
+    trace: Option<&mut Vec<TraceRound>>,
 ) -> bool {
     let mut queue: Vec<(char, i16, i16)> = vec![];
     let mut is_blocked = true;
     let mut total_activations_in_step: usize = 0;
+    let mut rules_fired: Vec<usize> = vec![];
+    let mut robot_steps: Vec<RobotStepTrace> = vec![];
 
     if let Some(last_state) = robots_history.last() {
         for (i, robot) in last_state.iter().enumerate() {
@@ -830,7 +1106,14 @@ fn simulate_step(
             }
 
             //     println!("Robot view: {:?}", robot_view);
-            if let Some((dir, color)) = find_matched_rule(&robot_view, final_rules) {
+            let rule_match = find_rule_match(&robot_view, final_rules);
+            if let Some(RuleMatch {
+                rule_index,
+                direction: dir,
+                color,
+                ..
+            }) = rule_match
+            {
                 let (x, y) = calculate_movement(&dir, &robot.1, &robot.2);
 
                 if x != robot.1 || y != robot.2 {
@@ -843,6 +1126,7 @@ fn simulate_step(
                 }
 
                 rules_count[i] += 1;
+                rules_fired.push(rule_index);
 
                 if dir == Direction::Idle {
                     idle_rules_count[i] += 1;
@@ -853,35 +1137,79 @@ fn simulate_step(
             } else {
                 queue.push(*robot);
             }
+
+            if trace.is_some() {
+                robot_steps.push(RobotStepTrace {
+                    robot: i,
+                    position: *robot,
+                    view: robot_view,
+                    matched_rule: rule_match,
+                    new_position: *queue.last().unwrap(),
+                });
+            }
+        }
+        if let Some(trace) = trace {
+            trace.push(TraceRound {
+                round: trace.len() + 1,
+                state: last_state.clone(),
+                robots: robot_steps,
+            });
         }
         robots_history.push(queue);
     }
     activations_per_step.push(total_activations_in_step);
+    rules_fired_per_step.push(rules_fired);
     is_blocked
 }
 
+/// One FSYNC round from `state`: the next state, and whether no robot matched a rule
+pub fn simulate_round(
+    state: &[Position],
+    final_rules: &[FinalRule],
+    grid_experiment: &GridExperiment,
+    visibility: i16,
+) -> (Vec<Position>, bool) {
+    let mut robots_history = vec![state.to_vec()];
+    let is_blocked = simulate_step(
+        &mut robots_history,
+        final_rules,
+        grid_experiment,
+        visibility,
+        &mut vec![0; state.len()],
+        &mut vec![0; state.len()],
+        &mut vec![0; state.len()],
+        &mut vec![0; state.len()],
+        &mut vec![],
+        &mut vec![],
+        None,
+    );
+    (robots_history.pop().unwrap(), is_blocked)
+}
+
 pub fn find_matched_rule(
     robot_view: &Vec<(char, i16, i16)>,
     final_rules: &[FinalRule],
 ) -> Option<(Direction, char)> {
-    let rotations_angles = vec![0, 90, 180, 270];
-    let suspected_final_rules = get_suspected_final_rules(&robot_view[0].0, final_rules);
-    //  println!("Suspected rules len: {:?}", suspected_final_rules.len());
-    /*  for (i, rule) in suspected_final_rules.iter().enumerate() {
-        println!("-----------------Suspected rule {}----------------: ", i);
-        print_final_rule(rule, visibility);
-    }*/
-    let mut matched_rule: Option<(Direction, char)> = None;
-    let mut match_count = 0;
+    find_rule_match(robot_view, final_rules).map(|rule_match| (rule_match.direction, rule_match.color))
+}
 
-    for rule in suspected_final_rules {
-        let rule_view = rule.view.clone();
-        let rule_direction = rule.direction.clone();
-        let rule_color = rule.color;
+/// Finds the rule (and its rotation) matching `robot_view`; panics if more than one matches
+pub fn find_rule_match(
+    robot_view: &Vec<(char, i16, i16)>,
+    final_rules: &[FinalRule],
+) -> Option<RuleMatch> {
+    let rotations_angles = [0, 90, 180, 270];
+    let mut matched_rule: Option<RuleMatch> = None;
+    let mut match_count = 0;
 
+    // Only rules whose robot has the same color as the observing robot can match
+    for (rule_index, rule) in final_rules
+        .iter()
+        .enumerate()
+        .filter(|(_, rule)| rule.view[0].0 == robot_view[0].0)
+    {
         for &angle in rotations_angles.iter() {
-            let rotated_view = rotate_view(&rule_view, angle);
-            let rotated_direction = rotate_direction(&rule_direction, angle);
+            let rotated_view = rotate_view(&rule.view, angle);
 
             if are_equivalent(robot_view, &rotated_view) {
                 match_count += 1;
@@ -890,7 +1218,12 @@ pub fn find_matched_rule(
                     panic!("Multiple matched rules found for the given robot view!");
                 }
 
-                matched_rule = Some((rotated_direction, rule_color));
+                matched_rule = Some(RuleMatch {
+                    rule_index,
+                    rotation: angle,
+                    direction: rotate_direction(&rule.direction, angle),
+                    color: rule.color,
+                });
             }
         }
     }
@@ -898,19 +1231,6 @@ pub fn find_matched_rule(
     matched_rule
 }
 
-fn get_suspected_final_rules(robot_marker: &char, final_rules: &[FinalRule]) -> Vec<FinalRule> {
-    final_rules
-        .iter()
-        .enumerate()
-        .filter_map(|(_, rule)| {
-            if rule.view[0].0 != *robot_marker {
-                return None;
-            }
-            Some(rule.clone()) // Return the rule itself
-        })
-        .collect()
-}
-
 pub fn calculate_view_with_walls(
     robot: (char, i16, i16),
     other_robots: &Vec<(char, i16, i16)>,
@@ -964,18 +1284,23 @@ pub fn calculate_view_with_walls(
     robots_view
 }
 
-pub fn calculate_final_rules(content: &str) -> (Vec<FinalRule>, i16) {
-    let (rules, visibility_range) = extract_rules_from_content(content);
+/// Final rules and visibility of an algorithm in any format `algorithm_loader` reads,
+/// detected from the content. Fails when the content cannot be parsed or holds no rule.
+pub fn calculate_final_rules(content: &str) -> Result<(Vec<FinalRule>, i16), String> {
+    let (rules, visibility_range) = parse_full_rules(content, None)?;
+    if rules.is_empty() {
+        return Err("No rules found".to_string());
+    }
     // println!("Visibility Range: {:?}", visibility_range);
     //println!("Validating algorithm: {} rules found", rules.len());
     let final_rules = convert_full_rules_to_final_rules(&rules, visibility_range);
     /*for rule in &final_rules {
         print_final_rule(&rule, visibility_range);
     }*/
-    (final_rules, visibility_range)
+    Ok((final_rules, visibility_range))
 }
 
-fn extract_rules_from_content(content: &str) -> (Vec<FullRule>, i16) {
+pub fn extract_rules_from_content(content: &str) -> (Vec<FullRule>, i16) {
     let content = content
         .lines()
         .filter(|line| !line.trim_start().starts_with('#'))
@@ -1058,24 +1383,25 @@ fn extract_rules_from_content(content: &str) -> (Vec<FullRule>, i16) {
     (rules, visibility_range)
 }
 
+/// Returns `(content, file_name)` for every algorithm file (`is_algorithm_file`), sorted by file name
+/// so that indices (and shard assignment) are stable across runs and machines.
 pub fn get_algo_files(path: &str) -> Vec<(String, String)> {
     let mut files_content = Vec::new();
 
     if let Ok(entries) = fs::read_dir(path) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if let Some(ext) = path.extension() {
-                if ext == "web-algo" {
-                    if let Ok(content) = fs::read_to_string(&path) {
-                        if let Some(file_name) = path.file_name().and_then(|name| name.to_str()) {
-                            files_content.push((content, file_name.to_string()));
-                        }
+            if is_algorithm_file(&path) {
+                if let Ok(content) = fs::read_to_string(&path) {
+                    if let Some(file_name) = path.file_name().and_then(|name| name.to_str()) {
+                        files_content.push((content, file_name.to_string()));
                     }
                 }
             }
         }
     }
 
+    files_content.sort_by(|a, b| a.1.cmp(&b.1));
     files_content
 }
 
@@ -1194,6 +1520,28 @@ pub fn generate_all_configs_with_positions_indices(
     configs
 }
 
+/// The configured initial configurations around `obstacle` whose robots all land on
+/// distinct free cells of a `columns` x `rows` grid
+pub fn configured_starts(columns: i16, rows: i16, obstacle: (i16, i16)) -> Vec<GridExperiment> {
+    generate_all_configs_with_positions_indices(
+        CONFIG.initial_configurations.clone(),
+        vec![(columns, rows, vec![obstacle])],
+    )
+    .into_iter()
+    .filter(|experiment| {
+        let positions = &experiment.init_config.initial_positions;
+        positions
+            .iter()
+            .all(|&(_, x, y)| (1..=columns).contains(&x) && (1..=rows).contains(&y))
+            && positions.iter().enumerate().all(|(i, &(_, x, y))| {
+                positions[i + 1..]
+                    .iter()
+                    .all(|&(_, other_x, other_y)| (x, y) != (other_x, other_y))
+            })
+    })
+    .collect()
+}
+
 fn print_grid(cols: usize, rows: usize, obstacle: (usize, usize)) {
     println!(
         "\nGrid ({}x{}) with obstacle at {:?}:\n",
@@ -1324,3 +1672,140 @@ pub fn validate_single_folder(folder_path: &str)->AlgorithmSnapshot {
 
     algorithm_snapshot
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::direction::rotate_direction;
+    use crate::modules::full_rule::FullRule;
+    use crate::validation::minimality::run_suite;
+
+    /// Two robots, visibility 1, validated on the experiments built by `fixture_experiments`
+    const FIXTURE: &str = include_str!("../data/fixtures/algo_1_act_37.web-algo");
+
+    /// Obstacle letter of the fixture experiments
+    const OBSTACLE: char = 'O';
+
+    /// A few experiments of the fixture's validation suite, fixed here so the tests do not
+    /// depend on the grids and initial configurations of config.json. The simulation still
+    /// reads the obstacle letter and the opacity from config.json, hence the assertion.
+    fn fixture_experiments(grid_definitions: Vec<(i16, i16, Vec<(i16, i16)>)>) -> Vec<GridExperiment> {
+        assert_eq!(CONFIG.obstacle, OBSTACLE, "config.json must use the fixture's obstacle letter");
+        let initial_configurations = vec![
+            (vec![(OBSTACLE, 0, 0), ('R', 1, 1), ('F', 0, 1)], false),
+            (vec![(OBSTACLE, 0, 0), ('R', 0, 1), ('F', -1, 1)], true),
+            (vec![(OBSTACLE, 0, 0), ('L', 2, 0), ('F', 1, 0)], false),
+            (vec![(OBSTACLE, 0, 0), ('L', -1, 0), ('F', -2, 0)], true),
+        ];
+        generate_all_configs_with_positions_indices(initial_configurations, grid_definitions)
+    }
+
+    fn full_rule(rows: &[&str], direction: Direction, color: char) -> FullRule {
+        FullRule {
+            view: rows.iter().map(|row| row.chars().collect()).collect(),
+            direction,
+            color,
+        }
+    }
+
+    #[test]
+    fn full_rule_view_is_centered_with_y_up() {
+        let rule = full_rule(&[" F ", "OL.", " . "], Direction::Left, 'R');
+        let final_rule = convert_full_rule_to_final_rule(&rule, 1);
+        assert_eq!(final_rule.view, vec![('L', 0, 0), ('F', 0, 1), ('O', -1, 0)]);
+        assert_eq!(final_rule.direction, Direction::Left);
+        assert_eq!(final_rule.color, 'R');
+
+        let rule = full_rule(
+            &["  W  ", " ... ", "..F.R", " .O. ", "  .  "],
+            Direction::Up,
+            'F',
+        );
+        let final_rule = convert_full_rule_to_final_rule(&rule, 2);
+        assert_eq!(
+            final_rule.view,
+            vec![('F', 0, 0), ('W', 0, 2), ('R', 2, 0), ('O', 0, -1)]
+        );
+    }
+
+    #[test]
+    fn exploration_finished_returns_the_cycle_length() {
+        let a = vec![('F', 1, 1), ('L', 2, 1)];
+        let b = vec![('F', 1, 2), ('L', 2, 2)];
+        let c = vec![('F', 1, 3), ('L', 2, 3)];
+        let a_reordered = vec![('L', 2, 1), ('F', 1, 1)];
+
+        assert_eq!(is_exploration_finished_(&vec![]), 0);
+        assert_eq!(is_exploration_finished_(&vec![a.clone()]), 0);
+        assert_eq!(is_exploration_finished_(&vec![a.clone(), b.clone(), c.clone()]), 0);
+        assert_eq!(is_exploration_finished_(&vec![a.clone(), b.clone(), a_reordered]), 2);
+        assert_eq!(
+            is_exploration_finished_(&vec![a.clone(), b.clone(), c.clone(), b.clone()]),
+            2
+        );
+        assert_eq!(is_exploration_finished_(&vec![a, b, c.clone(), c]), 1);
+    }
+
+    #[test]
+    fn rule_matching_is_invariant_under_rotation() {
+        let (final_rules, visibility) = calculate_final_rules(FIXTURE).unwrap();
+        assert_eq!(visibility, 1);
+        assert_eq!(final_rules.len(), 45);
+        for (rule_index, rule) in final_rules.iter().enumerate() {
+            for angle in [0, 90, 180, 270] {
+                let rule_match = find_rule_match(&rotate_view(&rule.view, angle), &final_rules)
+                    .expect("a rotated rule view matches its rule");
+                assert_eq!(rule_match.rule_index, rule_index);
+                assert_eq!(rule_match.rotation, angle);
+                assert_eq!(rule_match.direction, rotate_direction(&rule.direction, angle));
+                assert_eq!(rule_match.color, rule.color);
+            }
+        }
+    }
+
+    #[test]
+    fn unreadable_algorithms_are_errors() {
+        assert!(calculate_final_rules("visibilityRange: 1\n****** RULES ******\n").is_err());
+        assert!(calculate_final_rules("not an algorithm").is_err());
+    }
+
+    #[test]
+    fn fixture_explores_its_validation_grids() {
+        let (final_rules, visibility) = calculate_final_rules(FIXTURE).unwrap();
+        let list_of_grid_experiment =
+            fixture_experiments(vec![(7, 7, vec![(4, 4)]), (8, 9, vec![(5, 5)])]);
+        let (status, experiment_results) =
+            run_suite(&final_rules, visibility, &list_of_grid_experiment);
+        assert_eq!(status, AlgorithmStatus::Validated);
+        assert_eq!(experiment_results.len(), list_of_grid_experiment.len());
+
+        for result in &experiment_results {
+            assert!(result.cycle_len > 0);
+            let grid = &result.grid_experiment.grid_config;
+            let mut exploration_history = ExplorationHistory::new(grid);
+            let (obstacle_x, obstacle_y) = grid.obstacle_position;
+            exploration_history.set_positions(&[(OBSTACLE, obstacle_x, obstacle_y)], grid);
+            for robot in &result.robots_metrics {
+                exploration_history.set_positions(&robot.positions, grid);
+            }
+            assert!(
+                exploration_history.is_fully_explored(),
+                "experiment {} leaves {:?}",
+                result.experiment_id,
+                exploration_history.unvisited_cells(grid)
+            );
+        }
+    }
+
+    #[test]
+    fn fixture_without_a_rule_is_not_validated() {
+        let (final_rules, visibility) = calculate_final_rules(FIXTURE).unwrap();
+        let list_of_grid_experiment = fixture_experiments(vec![(7, 7, vec![(4, 4)])]);
+        for removed in [0, final_rules.len() - 1] {
+            let mut rules = final_rules.clone();
+            rules.remove(removed);
+            let (status, _) = run_suite(&rules, visibility, &list_of_grid_experiment);
+            assert_ne!(status, AlgorithmStatus::Validated, "without rule {}", removed);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+// ============================================================================
+// RULE MINIMALITY
+// ============================================================================
+//
+// Finds which rules of a validated algorithm are needed. The full validation
+// suite is run once to count how often each rule fires (`rule_fires`); rules
+// that never fire can be dropped for free. Every fired rule is then dropped
+// alone and the suite re-run. With `greedy`, rules are removed one at a time
+// in a few orders, keeping each removal that still validates, which gives
+// subsets where no single rule can be dropped. Each subset is written as a
+// `.web-algo` next to a JSON report in `_minimal/`.
+//
+// A subset "still validates" when every experiment validates; blocked
+// non-essential configurations are tolerated when the full algorithm is
+// itself not locally defined.
+//
+// ============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::{
+    methodology::{
+        configuration::CONFIG, globals::set_number_of_colors,
+        simulator::generate_web_algo_string_rule,
+    },
+    modules::{
+        algorithm_experiments_modules::{
+            algorithm_metrics::AlgorithmMetrics, experiment_result::ExperimentResult,
+        },
+        algorithm_status::AlgorithmStatus,
+        execution_status::ExecutionStatus,
+        final_rule::FinalRule,
+        grid_experiment::GridExperiment,
+        rule_minimality::{MinimalSubset, RuleMinimalityReport},
+        web_algo_generator::WebAlgoGenerator,
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::{build_grid_experiments, determine_algorithm_status, simulate_exploration},
+    },
+};
+
+pub const MINIMAL_FOLDER: &str = "_minimal";
+
+/// Greedy removal orders tried with `greedy`
+#[derive(Clone, Copy)]
+enum GreedyOrder {
+    LeastFiredFirst,
+    RuleOrder,
+    ReverseRuleOrder,
+}
+
+impl GreedyOrder {
+    const ALL: [GreedyOrder; 3] = [
+        GreedyOrder::LeastFiredFirst,
+        GreedyOrder::RuleOrder,
+        GreedyOrder::ReverseRuleOrder,
+    ];
+
+    /// Suffix of the `greedy_` strategy name
+    fn name(self) -> &'static str {
+        match self {
+            GreedyOrder::LeastFiredFirst => "least_fired_first",
+            GreedyOrder::RuleOrder => "rule_order",
+            GreedyOrder::ReverseRuleOrder => "reverse_rule_order",
+        }
+    }
+}
+
+/// Analyzes `algo_path` and returns the path of the written report
+pub fn analyze_rule_minimality(algo_path: &str, greedy: bool) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+    // The `@alias X` of the minimized files lists the colors of the generation globals
+    set_number_of_colors(CONFIG.number_of_colors);
+    let list_of_grid_experiment = build_grid_experiments();
+
+    let (status, experiment_results) = run_suite(&final_rules, visibility, &list_of_grid_experiment);
+    if status != AlgorithmStatus::Validated && status != AlgorithmStatus::ValidatedNotLd {
+        eprintln!(
+            "❌ {} is {}: rule minimality needs a validated algorithm",
+            algo.name,
+            status.label()
+        );
+        return None;
+    }
+
+    let mut rule_fires = vec![0; final_rules.len()];
+    for result in &experiment_results {
+        for (fires, count) in rule_fires.iter_mut().zip(&result.rule_fires) {
+            *fires += count;
+        }
+    }
+    let (fired, never_fired): (Vec<usize>, Vec<usize>) =
+        (0..final_rules.len()).partition(|&index| rule_fires[index] > 0);
+
+    let minimal_path = algo.output_folder(MINIMAL_FOLDER);
+    if let Err(e) = fs::create_dir_all(&minimal_path) {
+        eprintln!("❌ Failed to create {}: {}", minimal_path.display(), e);
+        return None;
+    }
+
+    let mut droppable_alone = Vec::new();
+    let mut necessary = Vec::new();
+    for &index in &fired {
+        let keep: Vec<usize> = (0..final_rules.len()).filter(|&rule| rule != index).collect();
+        if subset_validates(&final_rules, &keep, visibility, &list_of_grid_experiment, status) {
+            droppable_alone.push(index);
+        } else {
+            necessary.push(index);
+        }
+    }
+
+    let mut minimal_subsets: Vec<MinimalSubset> = Vec::new();
+    if !never_fired.is_empty() {
+        // Dropping rules that never matched cannot change any run
+        minimal_subsets.push(write_subset(
+            &minimal_path,
+            &algo.stem,
+            "fired_only",
+            fired.clone(),
+            status,
+            &final_rules,
+            visibility,
+        ));
+    }
+
+    if greedy {
+        for order in GreedyOrder::ALL {
+            let mut candidates = fired.clone();
+            match order {
+                GreedyOrder::LeastFiredFirst => candidates.sort_by_key(|&index| (rule_fires[index], index)),
+                GreedyOrder::RuleOrder => {}
+                GreedyOrder::ReverseRuleOrder => candidates.reverse(),
+            }
+
+            let mut keep = fired.clone();
+            for index in candidates {
+                let trial: Vec<usize> = keep.iter().copied().filter(|&rule| rule != index).collect();
+                if subset_validates(&final_rules, &trial, visibility, &list_of_grid_experiment, status) {
+                    keep = trial;
+                }
+            }
+
+            if let Some(same) = minimal_subsets.iter().find(|subset| subset.rules == keep) {
+                println!("  greedy_{} kept the same rules as {}", order.name(), same.strategy);
+                continue;
+            }
+            let keep_status = accepted_status(&final_rules, &keep, visibility, &list_of_grid_experiment);
+            minimal_subsets.push(write_subset(
+                &minimal_path,
+                &algo.stem,
+                &format!("greedy_{}", order.name()),
+                keep,
+                keep_status,
+                &final_rules,
+                visibility,
+            ));
+        }
+    }
+
+    let report = RuleMinimalityReport {
+        algorithm: algo.name.clone(),
+        status,
+        visibility,
+        rule_count: final_rules.len(),
+        rule_fires,
+        never_fired,
+        droppable_alone,
+        necessary,
+        minimal_subsets,
+    };
+    let report_path = minimal_path.join(format!("{}_minimality.json", algo.stem));
+    fs::write(
+        &report_path,
+        serde_json::to_string_pretty(&report).expect("Failed to serialize minimality report"),
+    )
+    .expect("Failed to write minimality report");
+
+    println!(
+        "{} {}: {} rules, {} never fired, {} droppable alone, {} necessary",
+        algo.name,
+        report.status.label(),
+        report.rule_count,
+        report.never_fired.len(),
+        report.droppable_alone.len(),
+        report.necessary.len()
+    );
+    if !report.never_fired.is_empty() {
+        println!("  Never fired: {:?}", report.never_fired);
+    }
+    if !report.droppable_alone.is_empty() {
+        println!("  Droppable alone: {:?}", report.droppable_alone);
+    }
+    for subset in &report.minimal_subsets {
+        println!(
+            "  {:<30} {:>4} rules {} → '{}'",
+            subset.strategy,
+            subset.rules.len(),
+            subset.status.label(),
+            minimal_path.join(&subset.file).display()
+        );
+    }
+    println!("Minimality report written to '{}'", report_path.display());
+    Some(report_path)
+}
+
+/// Runs every experiment (no early exit, so each result has its `rule_fires`)
+pub fn run_suite(
+    final_rules: &[FinalRule],
+    visibility: i16,
+    list_of_grid_experiment: &[GridExperiment],
+) -> (AlgorithmStatus, Vec<ExperimentResult>) {
+    let sim_results: Vec<_> = list_of_grid_experiment
+        .par_iter()
+        .enumerate()
+        .map(|(i, grid_experiment)| simulate_exploration(i, grid_experiment, final_rules, visibility))
+        .collect();
+    let (statuses, experiment_results): (Vec<_>, Vec<_>) = sim_results.into_iter().unzip();
+    let status = determine_algorithm_status(&AlgorithmMetrics::from_outcomes(&statuses));
+    (status, experiment_results)
+}
+
+/// Whether the rules in `keep` still validate; stops at the first failing experiment
+fn subset_validates(
+    final_rules: &[FinalRule],
+    keep: &[usize],
+    visibility: i16,
+    list_of_grid_experiment: &[GridExperiment],
+    original: AlgorithmStatus,
+) -> bool {
+    let subset: Vec<FinalRule> = keep.iter().map(|&index| final_rules[index].clone()).collect();
+    list_of_grid_experiment
+        .par_iter()
+        .enumerate()
+        .all(|(i, grid_experiment)| {
+            match simulate_exploration(i, grid_experiment, &subset, visibility).0 {
+                ExecutionStatus::Validated => true,
+                // Tolerated only when the full algorithm is not locally defined either
+                ExecutionStatus::BlockedNotEssential => original == AlgorithmStatus::ValidatedNotLd,
+                _ => false,
+            }
+        })
+}
+
+/// Status of a subset accepted by `subset_validates`
+fn accepted_status(
+    final_rules: &[FinalRule],
+    keep: &[usize],
+    visibility: i16,
+    list_of_grid_experiment: &[GridExperiment],
+) -> AlgorithmStatus {
+    let subset: Vec<FinalRule> = keep.iter().map(|&index| final_rules[index].clone()).collect();
+    run_suite(&subset, visibility, list_of_grid_experiment).0
+}
+
+fn write_subset(
+    minimal_path: &Path,
+    stem: &str,
+    strategy: &str,
+    rules: Vec<usize>,
+    status: AlgorithmStatus,
+    final_rules: &[FinalRule],
+    visibility: i16,
+) -> MinimalSubset {
+    let filename = format!("{}_min_{}", stem, strategy);
+    let mut generator =
+        WebAlgoGenerator::with_config(&filename, &minimal_path.to_string_lossy(), visibility);
+    for &index in &rules {
+        let rule = &final_rules[index];
+        generator.add_rule(&format!("# Rule: {}", index));
+        generator.add_rule(&generate_web_algo_string_rule(
+            &rule.view,
+            rule.direction.clone(),
+            rule.color,
+            visibility,
+        ));
+    }
+    generator.save_web_algo();
+
+    MinimalSubset {
+        strategy: strategy.to_string(),
+        rules,
+        status,
+        file: format!("{}.web-algo", filename),
+    }
+}
@@ -1,4 +1,18 @@
+pub mod algorithm_diff;
+pub mod algorithm_loader;
+pub mod catalog;
 pub mod initial_config_generator;
 pub mod initial_config_viewer;
 pub mod logger;
 pub mod logic;
+pub mod minimality;
+pub mod model_check;
+pub mod regress;
+pub mod rule_coverage_report;
+pub mod scaling;
+pub mod shard;
+pub mod state_graph;
+pub mod stepper;
+pub mod svg;
+pub mod tikz;
+pub mod trace;
@@ -0,0 +1,339 @@
+// ============================================================================
+// BOUNDED MODEL CHECKING
+// ============================================================================
+//
+// Checks an algorithm on every grid up to `max_columns` x `max_rows` and every
+// obstacle cell, instead of the validation grids only. The starts of a grid
+// are every placement of the configured number of robots on distinct free
+// cells, with every assignment of the configured colors (`all_starts`). The
+// full state graph reachable from them is built (`build_state_graph`): the
+// property "every reachable cycle covers the grid" fails for each cycle whose
+// states leave a cell unvisited. Starts that block are counted, not violations:
+// most placements of far apart robots see nothing and stop at once.
+//
+// Each violating cycle is reported with the start reaching it in the fewest
+// rounds; the one on the smallest grid is traced into `_model_check/` next to
+// the JSON report.
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use crate::{
+    methodology::configuration::CONFIG,
+    modules::{
+        color::get_colors,
+        exploration_history::ExplorationHistory,
+        final_rule::FinalRule,
+        grid_config::GridConfig,
+        grid_experiment::GridExperiment,
+        init_config::InitConfig,
+        model_check::{ModelCheckReport, ModelCheckVerdict, ModelCheckViolation},
+        position::Position,
+        state_graph::StateGraph,
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        state_graph::build_state_graph,
+        trace::{record_trace, write_trace},
+    },
+};
+
+pub const MODEL_CHECK_FOLDER: &str = "_model_check";
+
+/// Counts and violations of one grid size and obstacle placement
+struct GridCheck {
+    starts: usize,
+    states: usize,
+    cycles: usize,
+    blocked_starts: usize,
+    violations: Vec<ModelCheckViolation>,
+}
+
+/// Model checks `algo_path` on every grid up to `max_columns` x `max_rows`.
+/// `None` when the algorithm cannot be read, otherwise the verdict.
+pub fn model_check(algo_path: &str, max_columns: i16, max_rows: i16) -> Option<ModelCheckVerdict> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+
+    let mut grids = Vec::new();
+    for columns in 1..=max_columns {
+        for rows in 1..=max_rows {
+            for x in 1..=columns {
+                for y in 1..=rows {
+                    grids.push((columns, rows, (x, y)));
+                }
+            }
+        }
+    }
+    println!(
+        "Model checking {} on grids up to {}x{} ({} grid and obstacle placements, {} robots)",
+        algo.name,
+        max_columns,
+        max_rows,
+        grids.len(),
+        CONFIG.number_of_robots
+    );
+
+    let colors = get_colors(&CONFIG.all_color_letters, CONFIG.number_of_colors);
+    let checks: Vec<GridCheck> = grids
+        .par_iter()
+        .filter_map(|&(columns, rows, obstacle)| {
+            let starts = all_starts(columns, rows, obstacle, CONFIG.number_of_robots, &colors);
+            check_grid(&algo.name, &final_rules, visibility, &starts)
+        })
+        .collect();
+
+    let mut violations: Vec<ModelCheckViolation> = Vec::new();
+    for check in &checks {
+        violations.extend(check.violations.iter().cloned());
+    }
+    violations.sort_by_key(|violation| {
+        (
+            violation.columns * violation.rows,
+            violation.rounds,
+            violation.columns,
+            violation.obstacle_position,
+            violation.initial_positions.clone(),
+        )
+    });
+    let verdict = if violations.is_empty() {
+        ModelCheckVerdict::Proved
+    } else {
+        ModelCheckVerdict::Refuted
+    };
+
+    let model_check_path = algo.output_folder(MODEL_CHECK_FOLDER);
+    if let Err(e) = fs::create_dir_all(&model_check_path) {
+        eprintln!("❌ Failed to create {}: {}", model_check_path.display(), e);
+        return None;
+    }
+
+    let counterexample = violations.first().map(|violation| {
+        let grid_experiment = start_experiment(
+            0,
+            violation.columns,
+            violation.rows,
+            violation.obstacle_position,
+            violation.initial_positions.clone(),
+        );
+        let trace = record_trace(
+            algo.name.clone(),
+            &final_rules,
+            visibility,
+            grid_experiment.id,
+            &grid_experiment,
+        );
+        let trace_path = write_trace(
+            &trace,
+            &model_check_path,
+            &format!("{}_counterexample", algo.stem),
+        );
+        trace_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+
+    let report = ModelCheckReport {
+        algorithm: algo.name.clone(),
+        visibility,
+        max_columns,
+        max_rows,
+        grids_checked: checks.len(),
+        starts_checked: checks.iter().map(|check| check.starts).sum(),
+        states_explored: checks.iter().map(|check| check.states).sum(),
+        cycles_found: checks.iter().map(|check| check.cycles).sum(),
+        blocked_starts: checks.iter().map(|check| check.blocked_starts).sum(),
+        verdict,
+        violations,
+        counterexample,
+    };
+    let report_path = model_check_path.join(format!("{}_model_check.json", algo.stem));
+    fs::write(
+        &report_path,
+        serde_json::to_string_pretty(&report).expect("Failed to serialize model check report"),
+    )
+    .expect("Failed to write model check report");
+
+    println!(
+        "{} grids, {} starts, {} states, {} cycles, {} blocked starts",
+        report.grids_checked,
+        report.starts_checked,
+        report.states_explored,
+        report.cycles_found,
+        report.blocked_starts
+    );
+    match report.violations.first() {
+        None => println!("✅ Proved: every reachable cycle covers its grid"),
+        Some(violation) => {
+            println!("❌ Refuted: {} cycles miss cells", report.violations.len());
+            println!(
+                "  Smallest: {}x{} grid, obstacle {:?}, start {:?}, cycle of {} rounds reached after {} rounds",
+                violation.columns,
+                violation.rows,
+                violation.obstacle_position,
+                violation.initial_positions,
+                violation.cycle_len,
+                violation.rounds
+            );
+            println!("  Cells the cycle never visits: {:?}", violation.unvisited_cells);
+        }
+    }
+    println!("Model check report written to '{}'", report_path.display());
+    Some(verdict)
+}
+
+/// Every placement of `robot_count` robots on distinct free cells of a `columns` x `rows`
+/// grid with its obstacle at `obstacle`, with every assignment of `colors`. Robots are
+/// anonymous, so cells are chosen as sets: each global state appears once.
+pub fn all_starts(
+    columns: i16,
+    rows: i16,
+    obstacle: (i16, i16),
+    robot_count: usize,
+    colors: &[char],
+) -> Vec<GridExperiment> {
+    let free_cells: Vec<(i16, i16)> = (1..=columns)
+        .cartesian_product(1..=rows)
+        .filter(|&cell| cell != obstacle)
+        .collect();
+    if free_cells.len() < robot_count {
+        return Vec::new();
+    }
+
+    let mut starts = Vec::new();
+    for cells in free_cells.into_iter().combinations(robot_count) {
+        for robot_colors in (0..robot_count).map(|_| colors.iter().copied()).multi_cartesian_product() {
+            let mut positions: Vec<Position> = vec![(CONFIG.obstacle, obstacle.0, obstacle.1)];
+            positions.extend(
+                robot_colors
+                    .iter()
+                    .zip(&cells)
+                    .map(|(&color, &(x, y))| (color, x, y)),
+            );
+            starts.push(start_experiment(starts.len(), columns, rows, obstacle, positions));
+        }
+    }
+    starts
+}
+
+fn start_experiment(
+    id: usize,
+    columns: i16,
+    rows: i16,
+    obstacle: (i16, i16),
+    initial_positions: Vec<Position>,
+) -> GridExperiment {
+    GridExperiment {
+        id,
+        grid_config: GridConfig {
+            columns,
+            rows,
+            min_x: 0,
+            max_x: columns + 1,
+            min_y: 0,
+            max_y: rows + 1,
+            obstacle_position: obstacle,
+        },
+        init_config: InitConfig {
+            current_position_index: id,
+            initial_positions,
+            is_essential: false,
+        },
+    }
+}
+
+fn check_grid(
+    algorithm: &str,
+    final_rules: &[FinalRule],
+    visibility: i16,
+    starts: &[GridExperiment],
+) -> Option<GridCheck> {
+    let first = starts.first()?;
+    let indexed: Vec<(usize, &GridExperiment)> = starts
+        .iter()
+        .map(|experiment| (experiment.id, experiment))
+        .collect();
+    let graph = build_state_graph(algorithm, final_rules, visibility, 0, &indexed);
+
+    let mut successors: Vec<Option<usize>> = vec![None; graph.nodes.len()];
+    for edge in &graph.edges {
+        successors[edge.from] = Some(edge.to);
+    }
+
+    let mut check = GridCheck {
+        starts: starts.len(),
+        states: graph.nodes.len(),
+        cycles: graph.cycles.len(),
+        blocked_starts: 0,
+        violations: Vec::new(),
+    };
+    // Start reaching each cycle in the fewest rounds
+    let mut closest_start: HashMap<usize, (usize, &GridExperiment)> = HashMap::new();
+    for node in &graph.nodes {
+        for &experiment_id in &node.initial_experiments {
+            let start = &starts[experiment_id];
+            match reach_cycle(&graph, &successors, node.id) {
+                None => check.blocked_starts += 1,
+                Some((rounds, cycle)) => {
+                    let closest = closest_start.entry(cycle).or_insert((rounds, start));
+                    if rounds < closest.0 {
+                        *closest = (rounds, start);
+                    }
+                }
+            }
+        }
+    }
+
+    let grid = &first.grid_config;
+    for cycle in graph.cycles.iter().filter(|cycle| !cycle.explores) {
+        let Some(&(rounds, start)) = closest_start.get(&cycle.id) else {
+            continue;
+        };
+        let mut exploration_history = ExplorationHistory::new(grid);
+        for &node in &cycle.nodes {
+            exploration_history.set_positions(&graph.nodes[node].state, grid);
+        }
+        check.violations.push(ModelCheckViolation {
+            columns: grid.columns,
+            rows: grid.rows,
+            obstacle_position: grid.obstacle_position,
+            initial_positions: start.init_config.initial_positions.clone(),
+            rounds,
+            cycle_len: cycle.nodes.len(),
+            unvisited_cells: exploration_history.unvisited_cells(grid),
+        });
+    }
+    Some(check)
+}
+
+/// Follows the rounds from `start` until a blocked state or a state on a cycle.
+/// Returns the rounds taken and the cycle id, `None` when the execution blocks.
+fn reach_cycle(graph: &StateGraph, successors: &[Option<usize>], start: usize) -> Option<(usize, usize)> {
+    let mut current = start;
+    let mut rounds = 0;
+    loop {
+        if let Some(cycle) = graph.nodes[current].cycle {
+            return Some((rounds, cycle));
+        }
+        if graph.nodes[current].blocked {
+            return None;
+        }
+        // Every walk of `build_state_graph` ends blocked or on a recorded cycle
+        current = successors[current]?;
+        rounds += 1;
+    }
+}
@@ -0,0 +1,225 @@
+// ============================================================================
+// REGRESSION CHECK
+// ============================================================================
+//
+// Re-validates a folder of known-good algorithms and compares the outcome to
+// an expected-status manifest: the `AlgorithmStatus` of each file and, when
+// the manifest lists them, the status, `steps_taken` and `cycle_len` of every
+// experiment. Every experiment is run whatever the validation policy. Only
+// `update` writes the manifest; a missing one fails the check.
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    modules::{
+        algorithm_status::AlgorithmStatus,
+        regression_baseline::{BaselineAlgorithm, BaselineExperiment, RegressionBaseline},
+    },
+    validation::{
+        logic::{build_grid_experiments, calculate_final_rules, get_algo_files},
+        minimality::run_suite,
+    },
+};
+
+/// Experiment differences printed per algorithm before summarizing the rest
+const MAX_PRINTED_EXPERIMENTS: usize = 20;
+
+/// Checks `directory_path` against `manifest_path`; false when anything changed
+/// or the check could not run
+pub fn run_regression(directory_path: &str, manifest_path: &str, update: bool) -> bool {
+    if !update && !Path::new(manifest_path).exists() {
+        eprintln!(
+            "❌ Manifest not found: {} (run with --update to write a baseline)",
+            manifest_path
+        );
+        return false;
+    }
+
+    let algo_files = get_algo_files(directory_path);
+    if algo_files.is_empty() {
+        eprintln!("❌ No algorithm files in {}", directory_path);
+        return false;
+    }
+
+    let list_of_grid_experiment = build_grid_experiments();
+    println!(
+        "Validating {} algorithms on {} experiments",
+        algo_files.len(),
+        list_of_grid_experiment.len()
+    );
+    let current = RegressionBaseline {
+        experiment_count: Some(list_of_grid_experiment.len()),
+        algorithms: algo_files
+            .iter()
+            .map(|(content, file_name)| {
+                let (final_rules, visibility) = match calculate_final_rules(content) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        eprintln!("❌ {} is unreadable: {}", file_name, e);
+                        return BaselineAlgorithm {
+                            file: file_name.clone(),
+                            status: AlgorithmStatus::Unreadable,
+                            experiments: Vec::new(),
+                        };
+                    }
+                };
+                let (status, experiment_results) =
+                    run_suite(&final_rules, visibility, &list_of_grid_experiment);
+                BaselineAlgorithm {
+                    file: file_name.clone(),
+                    status,
+                    experiments: experiment_results
+                        .iter()
+                        .map(|result| BaselineExperiment {
+                            experiment_id: result.experiment_id,
+                            status: result.status.clone(),
+                            steps_taken: result.steps_taken,
+                            cycle_len: result.cycle_len,
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    };
+
+    if update {
+        let json = serde_json::to_string_pretty(&current).expect("Failed to serialize baseline");
+        if let Err(e) = fs::write(manifest_path, json) {
+            eprintln!("❌ Failed to write {}: {}", manifest_path, e);
+            return false;
+        }
+        println!(
+            "Baseline of {} algorithms written to '{}'",
+            current.algorithms.len(),
+            manifest_path
+        );
+        return true;
+    }
+
+    let baseline: RegressionBaseline = match fs::read_to_string(manifest_path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+    {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("❌ Failed to read manifest {}: {}", manifest_path, e);
+            return false;
+        }
+    };
+
+    let differences = compare(&baseline, &current);
+    if differences.is_empty() {
+        println!(
+            "✅ No regression: {} algorithms match '{}'",
+            baseline.algorithms.len(),
+            manifest_path
+        );
+        return true;
+    }
+    println!("❌ Regression against '{}':", manifest_path);
+    for difference in &differences {
+        println!("{}", difference);
+    }
+    false
+}
+
+/// One line per difference, grouped by algorithm
+fn compare(baseline: &RegressionBaseline, current: &RegressionBaseline) -> Vec<String> {
+    let mut differences = Vec::new();
+    if let (Some(expected), Some(actual)) = (baseline.experiment_count, current.experiment_count) {
+        if expected != actual {
+            differences.push(format!("  experiment count: {} → {}", expected, actual));
+        }
+    }
+
+    let current_by_file: HashMap<&str, &BaselineAlgorithm> = current
+        .algorithms
+        .iter()
+        .map(|algorithm| (algorithm.file.as_str(), algorithm))
+        .collect();
+    for expected in &baseline.algorithms {
+        let Some(actual) = current_by_file.get(expected.file.as_str()) else {
+            differences.push(format!("  {}: missing from the folder", expected.file));
+            continue;
+        };
+        if expected.status != actual.status {
+            differences.push(format!(
+                "  {}: {} → {}",
+                expected.file,
+                expected.status.label(),
+                actual.status.label()
+            ));
+        }
+
+        let actual_by_id: HashMap<usize, &BaselineExperiment> = actual
+            .experiments
+            .iter()
+            .map(|experiment| (experiment.experiment_id, experiment))
+            .collect();
+        let experiment_differences: Vec<String> = expected
+            .experiments
+            .iter()
+            .filter_map(|experiment| {
+                let Some(actual) = actual_by_id.get(&experiment.experiment_id) else {
+                    return Some(format!(
+                        "    {}: experiment {}: no longer run",
+                        expected.file, experiment.experiment_id
+                    ));
+                };
+                let mut changes = Vec::new();
+                if experiment.status != actual.status {
+                    changes.push(format!("status {:?} → {:?}", experiment.status, actual.status));
+                }
+                if experiment.steps_taken != actual.steps_taken {
+                    changes.push(format!(
+                        "steps_taken {} → {}",
+                        experiment.steps_taken, actual.steps_taken
+                    ));
+                }
+                if experiment.cycle_len != actual.cycle_len {
+                    changes.push(format!(
+                        "cycle_len {} → {}",
+                        experiment.cycle_len, actual.cycle_len
+                    ));
+                }
+                (!changes.is_empty()).then(|| {
+                    format!(
+                        "    {}: experiment {}: {}",
+                        expected.file,
+                        experiment.experiment_id,
+                        changes.join(", ")
+                    )
+                })
+            })
+            .collect();
+        let hidden = experiment_differences
+            .len()
+            .saturating_sub(MAX_PRINTED_EXPERIMENTS);
+        differences.extend(experiment_differences.into_iter().take(MAX_PRINTED_EXPERIMENTS));
+        if hidden > 0 {
+            differences.push(format!(
+                "    {}: ... and {} more experiments",
+                expected.file, hidden
+            ));
+        }
+    }
+
+    for actual in &current.algorithms {
+        if !baseline
+            .algorithms
+            .iter()
+            .any(|expected| expected.file == actual.file)
+        {
+            differences.push(format!(
+                "  {}: not in the manifest ({})",
+                actual.file,
+                actual.status.label()
+            ));
+        }
+    }
+    differences
+}
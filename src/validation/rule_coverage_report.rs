@@ -0,0 +1,269 @@
+// ============================================================================
+// RULE COVERAGE REPORT
+// ============================================================================
+//
+// `RuleCoverageLog` appends one `RuleCoverage` per algorithm (with per
+// experiment fires) to `rule_coverage.jsonl` as soon as it is validated, so
+// coverage is never held for the whole folder. `write_rule_coverage` then
+// builds `rule_coverage.html` from that file, one algorithm at a time.
+// The page flags, for each algorithm, the rules that never fired, fired only
+// in non-essential configurations, or fired only before the final cycle.
+//
+// ============================================================================
+
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::modules::{rule_coverage::RuleCoverage, viewer_assets::viewer_styles};
+
+const RULE_COVERAGE_JSONL: &str = "rule_coverage.jsonl";
+const RULE_COVERAGE_HTML: &str = "rule_coverage.html";
+
+/// `rule_coverage.jsonl` of a folder (or shard), in completion order
+pub struct RuleCoverageLog {
+    writer: BufWriter<File>,
+}
+
+impl RuleCoverageLog {
+    pub fn create(directory_path: &str) -> Self {
+        let file = File::create(Path::new(directory_path).join(RULE_COVERAGE_JSONL))
+            .expect("Unable to create rule_coverage.jsonl");
+        Self {
+            writer: BufWriter::new(file),
+        }
+    }
+
+    pub fn write(&mut self, coverage: &RuleCoverage) {
+        let line = serde_json::to_string(coverage).expect("Failed to serialize rule coverage");
+        writeln!(self.writer, "{}", line).expect("Failed to write rule_coverage.jsonl");
+    }
+
+    pub fn finish(mut self) {
+        self.writer.flush().expect("Failed to write rule_coverage.jsonl");
+    }
+}
+
+/// Concatenates the `rule_coverage.jsonl` of `shard_paths` into `directory_path`
+pub fn merge_rule_coverage(directory_path: &str, shard_paths: &[PathBuf]) {
+    let mut log = RuleCoverageLog::create(directory_path);
+    for shard_path in shard_paths {
+        if let Ok(file) = File::open(shard_path.join(RULE_COVERAGE_JSONL)) {
+            io::copy(&mut BufReader::new(file), &mut log.writer)
+                .expect("Failed to write rule_coverage.jsonl");
+        }
+    }
+    log.finish();
+}
+
+/// Writes `rule_coverage.html` from the `rule_coverage.jsonl` of `directory_path`,
+/// algorithms sorted by file index
+pub fn write_rule_coverage(directory_path: &str) {
+    let jsonl_path = Path::new(directory_path).join(RULE_COVERAGE_JSONL);
+    let html_path = Path::new(directory_path).join(RULE_COVERAGE_HTML);
+
+    if let Err(e) = rule_coverage_html(directory_path, &jsonl_path, &html_path) {
+        eprintln!("Failed to write {}: {}", html_path.display(), e);
+        return;
+    }
+
+    println!(
+        "Rule coverage written to '{}' and '{}'",
+        jsonl_path.display(),
+        html_path.display()
+    );
+}
+
+/// Reads one coverage line back
+fn read_coverage(line: &str) -> io::Result<RuleCoverage> {
+    serde_json::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Two passes over the JSONL: the summary table keeps one row per algorithm,
+/// then each section is read back at its offset and written straight to the page
+fn rule_coverage_html(directory_path: &str, jsonl_path: &Path, html_path: &Path) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(jsonl_path)?);
+    let mut lines: Vec<(usize, u64, String)> = Vec::new();
+    let mut offset = 0;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        if !line.trim().is_empty() {
+            let coverage = read_coverage(&line)?;
+            lines.push((coverage.algorithm_index, offset, summary_row(&coverage)));
+        }
+        offset += read as u64;
+    }
+    lines.sort_by_key(|(index, _, _)| *index);
+
+    let summary_rows: String = lines.iter().map(|(_, _, row)| row.as_str()).collect();
+    let page = page_html(directory_path, lines.len(), &summary_rows);
+    let (page_head, page_tail) = page.split_once(SECTIONS_MARKER).unwrap();
+
+    let mut html = BufWriter::new(File::create(html_path)?);
+    html.write_all(page_head.as_bytes())?;
+    for (_, offset, _) in &lines {
+        reader.seek(SeekFrom::Start(*offset))?;
+        line.clear();
+        reader.read_line(&mut line)?;
+        html.write_all(algorithm_section(&read_coverage(&line)?).as_bytes())?;
+    }
+    html.write_all(page_tail.as_bytes())?;
+    html.flush()
+}
+
+/// Where `page_html` leaves room for the algorithm sections
+const SECTIONS_MARKER: &str = "<!-- sections -->";
+
+fn summary_row(coverage: &RuleCoverage) -> String {
+    format!(
+        "<tr><td><a href=\"#algo-{index}\">{index}) {name}</a></td><td>{status}</td><td>{rules}</td><td>{experiments}</td><td>{dead}</td><td>{non_essential}</td><td>{before_cycle}</td></tr>\n",
+        index = coverage.algorithm_index,
+        name = escape_html(&coverage.algorithm),
+        status = coverage.status.label(),
+        rules = coverage.rules.len(),
+        experiments = coverage.experiments_run,
+        dead = count_cell(coverage.dead_rules.len()),
+        non_essential = count_cell(coverage.non_essential_only.len()),
+        before_cycle = count_cell(coverage.before_cycle_only.len()),
+    )
+}
+
+fn page_html(directory_path: &str, algorithm_count: usize, summary_rows: &str) -> String {
+    let bundled_css = viewer_styles();
+    let sections = SECTIONS_MARKER;
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Rule coverage - {title}</title>
+    {bundled_css}
+    <style>
+        body {{
+            background: #f5f5f5;
+        }}
+        .panel {{
+            background: #fff;
+            border: 1px solid #dee2e6;
+            border-radius: 8px;
+            padding: 16px;
+            margin-bottom: 16px;
+        }}
+        .rule-view {{
+            font-family: monospace;
+            font-size: 0.8rem;
+            white-space: pre;
+            margin: 0;
+        }}
+        .flag {{
+            display: inline-block;
+            border-radius: 4px;
+            padding: 1px 6px;
+            margin-right: 4px;
+            font-size: 0.75rem;
+            color: #fff;
+        }}
+        .flag-dead {{ background: #dc3545; }}
+        .flag-non-essential {{ background: #fd7e14; }}
+        .flag-before-cycle {{ background: #6f42c1; }}
+        tr.dead {{ background: #f8d7da; }}
+        tr.non-essential {{ background: #ffe5d0; }}
+        tr.before-cycle {{ background: #e2d9f3; }}
+        .flagged-only tr.plain {{ display: none; }}
+    </style>
+</head>
+<body>
+    <div class="container mt-4">
+        <h3>Rule coverage</h3>
+        <p class="text-muted">{title} &middot; {algorithm_count} algorithms &middot;
+            <span class="flag flag-dead">dead</span> never fired
+            <span class="flag flag-non-essential">non-essential only</span> fired only in non-essential configurations
+            <span class="flag flag-before-cycle">before cycle only</span> fired in experiments ending in a cycle, never inside it
+        </p>
+        <label><input type="checkbox" onchange="document.body.classList.toggle('flagged-only', this.checked)"> Show flagged rules only</label>
+        <div class="panel mt-4">
+            <div class="table-responsive">
+                <table class="table table-hover">
+                    <thead><tr><th>Algorithm</th><th>Status</th><th>Rules</th><th>Experiments</th><th>Dead</th><th>Non-essential only</th><th>Before cycle only</th></tr></thead>
+                    <tbody>
+{summary_rows}                    </tbody>
+                </table>
+            </div>
+        </div>
+{sections}    </div>
+</body>
+</html>
+"#,
+        title = escape_html(directory_path),
+    )
+}
+
+fn algorithm_section(coverage: &RuleCoverage) -> String {
+    let mut rows = String::new();
+    for (rule_index, rule) in coverage.rules.iter().enumerate() {
+        let mut flags = String::new();
+        let mut row_class = "plain";
+        if coverage.dead_rules.contains(&rule_index) {
+            flags.push_str("<span class=\"flag flag-dead\">dead</span>");
+            row_class = "dead";
+        }
+        if coverage.non_essential_only.contains(&rule_index) {
+            flags.push_str("<span class=\"flag flag-non-essential\">non-essential only</span>");
+            row_class = "non-essential";
+        }
+        if coverage.before_cycle_only.contains(&rule_index) {
+            flags.push_str("<span class=\"flag flag-before-cycle\">before cycle only</span>");
+            if row_class == "plain" {
+                row_class = "before-cycle";
+            }
+        }
+        let _ = writeln!(
+            rows,
+            "<tr class=\"{row_class}\"><td>{rule_index}</td><td><pre class=\"rule-view\">{view}</pre></td><td>{total}</td><td>{essential}</td><td>{cycle}</td><td>{flags}</td></tr>",
+            view = escape_html(rule),
+            total = coverage.total_fires[rule_index],
+            essential = coverage.essential_fires[rule_index],
+            cycle = coverage.cycle_fires[rule_index],
+        );
+    }
+
+    format!(
+        r#"        <div class="panel" id="algo-{index}">
+            <h5>{index}) {name} <small class="text-muted">{status} &middot; {experiments} experiments run</small></h5>
+            <div class="table-responsive">
+                <table class="table table-hover">
+                    <thead><tr><th>Rule</th><th>View &rarr; action</th><th>Fires</th><th>Essential</th><th>In cycle</th><th></th></tr></thead>
+                    <tbody>
+{rows}                    </tbody>
+                </table>
+            </div>
+        </div>
+"#,
+        index = coverage.algorithm_index,
+        name = escape_html(&coverage.algorithm),
+        status = coverage.status.label(),
+        experiments = coverage.experiments_run,
+    )
+}
+
+fn count_cell(count: usize) -> String {
+    if count == 0 {
+        "0".to_string()
+    } else {
+        format!("<strong>{}</strong>", count)
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
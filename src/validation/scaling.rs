@@ -0,0 +1,222 @@
+// ============================================================================
+// SCALING ANALYSIS
+// ============================================================================
+//
+// Runs an algorithm on every grid from the validation side (`basic_grid_len`)
+// up to `max_side` in both dimensions, with the obstacle at the center and
+// every configured initial configuration around it. Each run records the
+// round after which every cell has been visited and its cycle length. The
+// worst case of each grid is fitted against rows × columns, linearly and in
+// log-log (the exponent tells O(n·m) from a blow-up). Written to `_scaling/`
+// as a JSON report and a per-run CSV ready to plot.
+//
+// ============================================================================
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::{
+    methodology::configuration::CONFIG,
+    modules::{
+        algorithm_experiments_modules::experiment_result::ExperimentResult,
+        execution_status::ExecutionStatus,
+        exploration_history::ExplorationHistory,
+        scaling::{GridScaling, ScalingFit, ScalingReport, ScalingRow},
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::{basic_grid_len, configured_starts, simulate_exploration},
+    },
+};
+
+pub const SCALING_FOLDER: &str = "_scaling";
+
+/// Runs the scaling analysis of `algo_path` and returns the report path
+pub fn analyze_scaling(algo_path: &str, max_side: i16) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let min_side = basic_grid_len();
+    if max_side < min_side {
+        eprintln!(
+            "❌ The largest side must be at least the validation side {}, got {}",
+            min_side, max_side
+        );
+        return None;
+    }
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+
+    let mut sizes = Vec::new();
+    for columns in min_side..=max_side {
+        for rows in min_side..=max_side {
+            sizes.push((columns, rows));
+        }
+    }
+    println!(
+        "Scaling {} on {} grids from {}x{} to {}x{}",
+        algo.name,
+        sizes.len(),
+        min_side,
+        min_side,
+        max_side,
+        max_side
+    );
+
+    let rows_per_grid: Vec<Vec<ScalingRow>> = sizes
+        .par_iter()
+        .map(|&(columns, rows)| {
+            let obstacle = ((columns + 1) / 2, (rows + 1) / 2);
+            configured_starts(columns, rows, obstacle)
+                .iter()
+                .map(|grid_experiment| {
+                    let (status, result) =
+                        simulate_exploration(grid_experiment.id, grid_experiment, &final_rules, visibility);
+                    scaling_row(status, &result)
+                })
+                .collect()
+        })
+        .collect();
+
+    let grids: Vec<GridScaling> = sizes
+        .iter()
+        .zip(&rows_per_grid)
+        .map(|(&(columns, rows), runs)| {
+            let validated: Vec<&ScalingRow> = runs
+                .iter()
+                .filter(|row| row.status == ExecutionStatus::Validated)
+                .collect();
+            GridScaling {
+                columns,
+                rows,
+                area: columns as i32 * rows as i32,
+                runs: runs.len(),
+                validated: validated.len(),
+                max_rounds_to_cover: validated.iter().filter_map(|row| row.rounds_to_cover).max(),
+                max_cycle_len: validated.iter().map(|row| row.cycle_len).max(),
+            }
+        })
+        .collect();
+
+    let scaling_path = algo.output_folder(SCALING_FOLDER);
+    if let Err(e) = fs::create_dir_all(&scaling_path) {
+        eprintln!("❌ Failed to create {}: {}", scaling_path.display(), e);
+        return None;
+    }
+
+    let csv_name = format!("{}_scaling.csv", algo.stem);
+    let mut csv = BufWriter::new(
+        File::create(scaling_path.join(&csv_name)).expect("Unable to create scaling CSV"),
+    );
+    writeln!(csv, "{}", ScalingRow::CSV_HEADER).expect("Failed to write scaling CSV");
+    for row in rows_per_grid.iter().flatten() {
+        writeln!(csv, "{}", row.to_csv_line()).expect("Failed to write scaling CSV");
+    }
+    csv.flush().expect("Failed to write scaling CSV");
+
+    let worst_case = |metric: fn(&GridScaling) -> Option<usize>| -> Vec<(f64, f64)> {
+        grids
+            .iter()
+            .filter_map(|grid| Some((grid.area as f64, metric(grid)? as f64)))
+            .collect()
+    };
+    let report = ScalingReport {
+        algorithm: algo.name.clone(),
+        visibility,
+        min_side,
+        max_side,
+        rounds_to_cover_fit: ScalingFit::fit(
+            "rounds_to_cover",
+            &worst_case(|grid| grid.max_rounds_to_cover),
+        ),
+        cycle_len_fit: ScalingFit::fit("cycle_len", &worst_case(|grid| grid.max_cycle_len)),
+        grids,
+        csv: csv_name.clone(),
+    };
+    let report_path = scaling_path.join(format!("{}_scaling.json", algo.stem));
+    fs::write(
+        &report_path,
+        serde_json::to_string_pretty(&report).expect("Failed to serialize scaling report"),
+    )
+    .expect("Failed to write scaling report");
+
+    let failing: Vec<String> = report
+        .grids
+        .iter()
+        .filter(|grid| grid.validated < grid.runs)
+        .map(|grid| format!("{}x{} ({}/{})", grid.columns, grid.rows, grid.validated, grid.runs))
+        .collect();
+    if !failing.is_empty() {
+        println!("⚠️  Not every run validates on: {}", failing.join(", "));
+    }
+    for fit in [&report.rounds_to_cover_fit, &report.cycle_len_fit]
+        .into_iter()
+        .flatten()
+    {
+        let power_law = match (fit.exponent, fit.log_r_squared) {
+            (Some(exponent), Some(log_r_squared)) => {
+                format!(", ~ area^{:.3} (r² {:.3})", exponent, log_r_squared)
+            }
+            _ => String::new(),
+        };
+        println!(
+            "  {:<16} {:.3} · area + {:.2} (r² {:.3}){}",
+            fit.metric, fit.slope, fit.intercept, fit.r_squared, power_law
+        );
+    }
+    println!(
+        "Scaling report written to '{}' and '{}'",
+        report_path.display(),
+        scaling_path.join(&csv_name).display()
+    );
+    Some(report_path)
+}
+
+fn scaling_row(status: ExecutionStatus, result: &ExperimentResult) -> ScalingRow {
+    let grid_experiment = &result.grid_experiment;
+    let grid = &grid_experiment.grid_config;
+    ScalingRow {
+        columns: grid.columns,
+        rows: grid.rows,
+        area: grid.columns as i32 * grid.rows as i32,
+        obstacle_x: grid.obstacle_position.0,
+        obstacle_y: grid.obstacle_position.1,
+        initial_positions_index: grid_experiment.init_config.current_position_index,
+        is_essential: grid_experiment.init_config.is_essential,
+        status,
+        rounds_to_cover: rounds_to_cover(result),
+        steps_taken: result.steps_taken,
+        cycle_len: result.cycle_len,
+    }
+}
+
+/// First round after which the robots (and the obstacle cell) cover the grid
+fn rounds_to_cover(result: &ExperimentResult) -> Option<usize> {
+    let grid = &result.grid_experiment.grid_config;
+    let mut exploration_history = ExplorationHistory::new(grid);
+    let (obstacle_x, obstacle_y) = grid.obstacle_position;
+    exploration_history.set_positions(&[(CONFIG.obstacle, obstacle_x, obstacle_y)], grid);
+
+    let rounds = result
+        .robots_metrics
+        .iter()
+        .map(|robot| robot.positions.len())
+        .max()?;
+    (0..rounds).find(|&round| {
+        let positions: Vec<_> = result
+            .robots_metrics
+            .iter()
+            .filter_map(|robot| robot.positions.get(round).copied())
+            .collect();
+        exploration_history.set_positions(&positions, grid);
+        exploration_history.is_fully_explored()
+    })
+}
@@ -0,0 +1,260 @@
+// ============================================================================
+// SHARDED VALIDATION
+// ============================================================================
+//
+// Splits the validation of one folder across several processes or machines.
+//
+// **How it works:**
+// 1. `validate_shard` validates every file whose index `i` in the sorted
+//    `get_algo_files` list satisfies `i % n == shard` and writes its output to
+//    `<folder>/_shards/shard_<i>_of_<n>/` (`shard_result.json`, `_details/`
+//    and its own `results.jsonl`, `results.csv` and `rule_coverage.jsonl`)
+// 2. `merge_shards` reads all shard folders back, copies the `_details` files,
+//    concatenates the results tables and rule coverage in shard order and writes the same
+//    `_validation_results.log`, `_blocked_summaries.log` and classification a
+//    single-process run would produce
+//
+// ============================================================================
+
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::methodology::configuration::config_fingerprint;
+use crate::modules::{
+    algorithm_snapshot::AlgorithmSnapshot, algorithm_stats::AlgorithmStats,
+    algorithm_validation_record::AlgorithmValidationRecord,
+    blocked_config_summary::BlockedConfigSummary, shard_result::ShardResult,
+    shard_spec::ShardSpec,
+};
+use crate::validation::logger::{log_all_possible_configurations, merge_results_tables};
+use crate::validation::rule_coverage_report::merge_rule_coverage;
+use crate::validation::logic::{
+    create_validation_configs, get_algo_files, run_all_algos, write_validation_outputs,
+};
+
+pub const SHARDS_FOLDER: &str = "_shards";
+const SHARD_RESULT_FILE: &str = "shard_result.json";
+
+/// Validates the subset of `folder_path` selected by `shard`
+pub fn validate_shard(folder_path: &str, shard: ShardSpec) {
+    println!(
+        "🧩 Validating shard {}/{} of folder: {}",
+        shard.index, shard.count, folder_path
+    );
+
+    let list_of_grid_experiment = create_validation_configs(folder_path);
+    let algo_files = get_algo_files(folder_path);
+    let shard_path = Path::new(folder_path)
+        .join(SHARDS_FOLDER)
+        .join(shard.folder_name());
+    let shard_path_str = shard_path.to_string_lossy().into_owned();
+
+    if let Err(e) = fs::create_dir_all(&shard_path) {
+        eprintln!("Failed to create shard folder {}: {}", shard_path_str, e);
+        return;
+    }
+
+    let selected = (0..algo_files.len()).filter(|i| shard.contains(*i)).count();
+    println!(
+        "Found {} algorithm files, {} assigned to this shard",
+        algo_files.len(),
+        selected
+    );
+
+    let algo_stats = Arc::new(AlgorithmStats::default());
+    let results = Arc::new(Mutex::new(Vec::<AlgorithmValidationRecord>::new()));
+    let blocked_summaries = Arc::new(Mutex::new(Vec::<BlockedConfigSummary>::new()));
+
+    if selected > 0 {
+        run_all_algos(
+            &algo_files,
+            &list_of_grid_experiment,
+            &algo_stats,
+            &results,
+            &blocked_summaries,
+            &shard_path_str,
+            Some(shard),
+        );
+    }
+
+    let shard_result = ShardResult {
+        shard,
+        algo_file_names: algo_files.iter().map(|(_, name)| name.clone()).collect(),
+        config_fingerprint: config_fingerprint(),
+        grid_experiments: list_of_grid_experiment,
+        records: results.lock().unwrap().clone(),
+        blocked_summaries: blocked_summaries.lock().unwrap().clone(),
+    };
+
+    let result_path = shard_path.join(SHARD_RESULT_FILE);
+    let json_data = serde_json::to_string_pretty(&shard_result).unwrap();
+    fs::write(&result_path, json_data).expect("Failed to write shard result");
+    println!("Shard result written to '{}'", result_path.display());
+}
+
+/// Combines every shard of `folder_path` into the folder-level validation output.
+/// Returns `None` (after printing why) when shards are missing or inconsistent.
+pub fn merge_shards(folder_path: &str) -> Option<AlgorithmSnapshot> {
+    let shards_path = Path::new(folder_path).join(SHARDS_FOLDER);
+    let shard_results = match read_shard_results(&shards_path) {
+        Ok(results) => results,
+        Err(message) => {
+            eprintln!("❌ {}", message);
+            return None;
+        }
+    };
+
+    if let Err(message) = check_shards_complete(&shard_results) {
+        eprintln!("❌ {}", message);
+        return None;
+    }
+
+    let first = &shard_results[0];
+    println!(
+        "🧩 Merging {} shards ({} algorithm files) into {}",
+        first.shard.count,
+        first.algo_file_names.len(),
+        folder_path
+    );
+
+    let algo_files: Vec<(String, String)> = first
+        .algo_file_names
+        .iter()
+        .map(|name| (String::new(), name.clone()))
+        .collect();
+    let list_of_grid_experiment = first.grid_experiments.clone();
+
+    log_all_possible_configurations(folder_path, &list_of_grid_experiment)
+        .expect("Failed to log configurations in folder");
+
+    let algo_stats = Arc::new(AlgorithmStats::default());
+    let mut records = Vec::new();
+    let mut blocked = Vec::new();
+    let mut shard_paths = Vec::new();
+    for shard_result in &shard_results {
+        for record in &shard_result.records {
+            algo_stats.insert(record.status, &record.file_name);
+        }
+        records.extend(shard_result.records.iter().cloned());
+        blocked.extend(shard_result.blocked_summaries.iter().cloned());
+
+        let shard_path = shards_path.join(shard_result.shard.folder_name());
+        copy_details(&shard_path.join("_details"), &Path::new(folder_path).join("_details"));
+        shard_paths.push(shard_path);
+    }
+    merge_results_tables(folder_path, &shard_paths);
+    merge_rule_coverage(folder_path, &shard_paths);
+
+    let results = Arc::new(Mutex::new(records));
+    let blocked_summaries = Arc::new(Mutex::new(blocked));
+
+    Some(write_validation_outputs(
+        folder_path,
+        &algo_files,
+        &list_of_grid_experiment,
+        &algo_stats,
+        &results,
+        &blocked_summaries,
+    ))
+}
+
+fn read_shard_results(shards_path: &Path) -> Result<Vec<ShardResult>, String> {
+    let entries = fs::read_dir(shards_path)
+        .map_err(|_| format!("No shard folder found at {}", shards_path.display()))?;
+
+    let mut shard_results = Vec::new();
+    for entry in entries.flatten() {
+        let result_path = entry.path().join(SHARD_RESULT_FILE);
+        if !result_path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&result_path)
+            .map_err(|e| format!("Failed to read {}: {}", result_path.display(), e))?;
+        let shard_result = serde_json::from_str::<ShardResult>(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", result_path.display(), e))?;
+        shard_results.push(shard_result);
+    }
+
+    if shard_results.is_empty() {
+        return Err(format!("No shard results found in {}", shards_path.display()));
+    }
+    shard_results.sort_by_key(|result| result.shard.index);
+    Ok(shard_results)
+}
+
+fn check_shards_complete(shard_results: &[ShardResult]) -> Result<(), String> {
+    let first = &shard_results[0];
+    let count = first.shard.count;
+    let current_fingerprint = config_fingerprint();
+
+    for shard_result in shard_results {
+        // The merge writes its logs and classification with the current config
+        if shard_result.config_fingerprint != current_fingerprint {
+            return Err(format!(
+                "Shard {} was run with config {}, the current config.json is {}",
+                shard_result.shard.index,
+                if shard_result.config_fingerprint.is_empty() {
+                    "unknown"
+                } else {
+                    shard_result.config_fingerprint.as_str()
+                },
+                current_fingerprint
+            ));
+        }
+        if shard_result.shard.count != count {
+            return Err(format!(
+                "Shard {} was run with {} shards, expected {}",
+                shard_result.shard.index, shard_result.shard.count, count
+            ));
+        }
+        if shard_result.algo_file_names != first.algo_file_names {
+            return Err(format!(
+                "Shard {} saw a different set of algorithm files",
+                shard_result.shard.index
+            ));
+        }
+        if shard_result.grid_experiments != first.grid_experiments {
+            return Err(format!(
+                "Shard {} used different configurations ({}, expected {})",
+                shard_result.shard.index,
+                shard_result.grid_experiments.len(),
+                first.grid_experiments.len()
+            ));
+        }
+    }
+
+    let missing: Vec<usize> = (0..count)
+        .filter(|index| !shard_results.iter().any(|r| r.shard.index == *index))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Missing shards {:?} of {}", missing, count));
+    }
+    if shard_results.len() != count {
+        return Err(format!(
+            "Found {} shard results for {} shards",
+            shard_results.len(),
+            count
+        ));
+    }
+
+    Ok(())
+}
+
+fn copy_details(from: &Path, to: &Path) {
+    let entries = match fs::read_dir(from) {
+        Ok(entries) => entries,
+        Err(_) => return, // shard had no validated algorithms
+    };
+    fs::create_dir_all(to).expect("Failed to create _details folder");
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().map_or(false, |ext| ext == "json") {
+            if let Some(file_name) = path.file_name() {
+                if let Err(e) = fs::copy(&path, to.join(file_name)) {
+                    eprintln!("Failed to copy {}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,309 @@
+// ============================================================================
+// STATE GRAPH
+// ============================================================================
+//
+// Exports the configuration graph an algorithm induces on a grid: nodes are
+// global states, edges FSYNC rounds (`simulate_round`). Every initial
+// configuration of the grid is walked until it reaches a known state, gets
+// blocked, or closes a cycle (`is_exploration_finished_`). Cycles record
+// whether they explore the whole grid. Written as DOT and JSON to `_graphs/`.
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    methodology::configuration::CONFIG,
+    modules::{
+        exploration_history::ExplorationHistory,
+        final_rule::FinalRule,
+        grid_experiment::GridExperiment,
+        position::Position,
+        state_graph::{StateCycle, StateEdge, StateGraph, StateNode},
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::{build_grid_experiments, is_exploration_finished_, simulate_round},
+    },
+};
+
+pub const GRAPHS_FOLDER: &str = "_graphs";
+
+/// Writes the state graph of `algo_path` for grid `grid_index`, or for every grid.
+/// Grids are numbered in the order they first appear in the validation experiments.
+pub fn export_state_graphs(algo_path: &str, grid_index: Option<usize>) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let list_of_grid_experiment = build_grid_experiments();
+    let grids = group_by_grid(&list_of_grid_experiment);
+    let selected: Vec<usize> = match grid_index {
+        Some(index) if index < grids.len() => vec![index],
+        Some(index) => {
+            eprintln!(
+                "❌ Grid {} does not exist ({} grids, ids 0..{})",
+                index,
+                grids.len(),
+                grids.len().saturating_sub(1)
+            );
+            return None;
+        }
+        None => (0..grids.len()).collect(),
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+    let graphs_path = algo.output_folder(GRAPHS_FOLDER);
+    if let Err(e) = fs::create_dir_all(&graphs_path) {
+        eprintln!("❌ Failed to create {}: {}", graphs_path.display(), e);
+        return None;
+    }
+    for index in selected {
+        let graph = build_state_graph(&algo.name, &final_rules, visibility, index, &grids[index]);
+        let file_stem = format!("{}_grid_{}", algo.stem, index);
+        let json_path = graphs_path.join(format!("{}.json", file_stem));
+        let dot_path = graphs_path.join(format!("{}.dot", file_stem));
+        fs::write(
+            &json_path,
+            serde_json::to_string_pretty(&graph).expect("Failed to serialize state graph"),
+        )
+        .expect("Failed to write state graph JSON");
+        fs::write(&dot_path, state_graph_dot(&graph)).expect("Failed to write state graph DOT");
+
+        let grid = &graph.grid_config;
+        println!(
+            "Grid {} ({}x{}, obstacle {:?}): {} states, {} edges, {} cycles ({} exploring), {} blocked, {} initial → '{}'",
+            index,
+            grid.columns,
+            grid.rows,
+            grid.obstacle_position,
+            graph.nodes.len(),
+            graph.edges.len(),
+            graph.cycles.len(),
+            graph.cycles.iter().filter(|cycle| cycle.explores).count(),
+            graph.nodes.iter().filter(|node| node.blocked).count(),
+            graph
+                .nodes
+                .iter()
+                .filter(|node| !node.initial_experiments.is_empty())
+                .count(),
+            dot_path.display()
+        );
+    }
+    Some(graphs_path)
+}
+
+/// Experiments grouped by grid (bounds and obstacle), in first-seen order
+fn group_by_grid(list_of_grid_experiment: &[GridExperiment]) -> Vec<Vec<(usize, &GridExperiment)>> {
+    let mut grids: Vec<Vec<(usize, &GridExperiment)>> = Vec::new();
+    for (experiment_id, grid_experiment) in list_of_grid_experiment.iter().enumerate() {
+        let key = grid_key(grid_experiment);
+        match grids
+            .iter_mut()
+            .find(|experiments| grid_key(experiments[0].1) == key)
+        {
+            Some(experiments) => experiments.push((experiment_id, grid_experiment)),
+            None => grids.push(vec![(experiment_id, grid_experiment)]),
+        }
+    }
+    grids
+}
+
+fn grid_key(grid_experiment: &GridExperiment) -> (i16, i16, i16, i16, (i16, i16)) {
+    let grid = &grid_experiment.grid_config;
+    (
+        grid.min_x,
+        grid.max_x,
+        grid.min_y,
+        grid.max_y,
+        grid.obstacle_position,
+    )
+}
+
+pub fn build_state_graph(
+    algorithm: &str,
+    final_rules: &[FinalRule],
+    visibility: i16,
+    grid_index: usize,
+    experiments: &[(usize, &GridExperiment)],
+) -> StateGraph {
+    let grid_config = experiments[0].1.grid_config.clone();
+    let mut graph = StateGraph {
+        algorithm: algorithm.to_string(),
+        grid_index,
+        grid_config,
+        visibility,
+        nodes: Vec::new(),
+        edges: Vec::new(),
+        cycles: Vec::new(),
+    };
+    let mut node_ids: HashMap<Vec<Position>, usize> = HashMap::new();
+    // Successor computed (or blocked): walks stop on reaching such a node
+    let mut expanded: Vec<bool> = Vec::new();
+
+    for &(experiment_id, grid_experiment) in experiments {
+        let initial_state = &grid_experiment.init_config.initial_positions;
+        let start = node_for(&mut graph, &mut node_ids, &mut expanded, initial_state);
+        graph.nodes[start].initial_experiments.push(experiment_id);
+
+        let mut robots_history = vec![initial_state.clone()];
+        loop {
+            let current = node_for(
+                &mut graph,
+                &mut node_ids,
+                &mut expanded,
+                robots_history.last().unwrap(),
+            );
+            if expanded[current] {
+                break;
+            }
+            expanded[current] = true;
+
+            let (next_state, is_blocked) = simulate_round(
+                robots_history.last().unwrap(),
+                final_rules,
+                grid_experiment,
+                visibility,
+            );
+            if is_blocked {
+                graph.nodes[current].blocked = true;
+                break;
+            }
+            let next = node_for(&mut graph, &mut node_ids, &mut expanded, &next_state);
+            graph.edges.push(StateEdge {
+                from: current,
+                to: next,
+            });
+            robots_history.push(next_state);
+
+            let cycle_len = is_exploration_finished_(&robots_history);
+            if cycle_len > 0 {
+                if graph.nodes[next].cycle.is_none() {
+                    let end = robots_history.len() - 1;
+                    let cycle_states = &robots_history[end - cycle_len..end];
+                    record_cycle(&mut graph, &node_ids, cycle_states);
+                }
+                break;
+            }
+        }
+    }
+    graph
+}
+
+/// Node of `state`, created unexpanded if new
+fn node_for(
+    graph: &mut StateGraph,
+    node_ids: &mut HashMap<Vec<Position>, usize>,
+    expanded: &mut Vec<bool>,
+    state: &[Position],
+) -> usize {
+    let mut key = state.to_vec();
+    key.sort_unstable();
+    *node_ids.entry(key.clone()).or_insert_with(|| {
+        graph.nodes.push(StateNode {
+            id: graph.nodes.len(),
+            state: key,
+            initial_experiments: Vec::new(),
+            blocked: false,
+            cycle: None,
+        });
+        expanded.push(false);
+        graph.nodes.len() - 1
+    })
+}
+
+fn record_cycle(graph: &mut StateGraph, node_ids: &HashMap<Vec<Position>, usize>, cycle_states: &[Vec<Position>]) {
+    let mut exploration_history = ExplorationHistory::new(&graph.grid_config);
+    let mut nodes = Vec::with_capacity(cycle_states.len());
+    for state in cycle_states {
+        exploration_history.set_positions(state, &graph.grid_config);
+        let mut key = state.clone();
+        key.sort_unstable();
+        nodes.push(node_ids[&key]);
+    }
+
+    let id = graph.cycles.len();
+    for &node in &nodes {
+        graph.nodes[node].cycle = Some(id);
+    }
+    graph.cycles.push(StateCycle {
+        id,
+        nodes,
+        explores: exploration_history.is_fully_explored(),
+    });
+}
+
+/// Initial states have a double border, blocked states are red, cycle states are
+/// green when the cycle explores the grid and yellow otherwise
+pub fn state_graph_dot(graph: &StateGraph) -> String {
+    let grid = &graph.grid_config;
+    let mut dot = String::new();
+    let _ = writeln!(
+        dot,
+        "// {} on grid {} ({}x{}, obstacle {:?}), visibility {}",
+        graph.algorithm,
+        graph.grid_index,
+        grid.columns,
+        grid.rows,
+        grid.obstacle_position,
+        graph.visibility
+    );
+    dot.push_str("// double border: initial | red: blocked | green: exploring cycle | yellow: non-exploring cycle\n");
+    dot.push_str("digraph state_graph {\n");
+    dot.push_str("  rankdir=LR;\n");
+    dot.push_str("  node [shape=box, fontname=\"monospace\", fontsize=10, style=filled, fillcolor=white];\n");
+
+    for node in &graph.nodes {
+        let robots: Vec<String> = node
+            .state
+            .iter()
+            .filter(|(letter, _, _)| *letter != CONFIG.obstacle)
+            .map(|(letter, x, y)| format!("{}({},{})", letter, x, y))
+            .collect();
+        let mut label = format!("#{}\\n{}", node.id, robots.join(" "));
+        let mut attributes = Vec::new();
+        if !node.initial_experiments.is_empty() {
+            let ids: Vec<String> = node
+                .initial_experiments
+                .iter()
+                .map(|id| id.to_string())
+                .collect();
+            let _ = write!(label, "\\ninit: {}", ids.join(","));
+            attributes.push("peripheries=2".to_string());
+        }
+        if node.blocked {
+            label.push_str("\\nBLOCKED");
+            attributes.push("fillcolor=\"#f8d7da\"".to_string());
+        } else if let Some(cycle) = node.cycle {
+            let explores = graph.cycles[cycle].explores;
+            let _ = write!(label, "\\ncycle {}", cycle);
+            attributes.push(format!(
+                "fillcolor=\"{}\"",
+                if explores { "#d1e7dd" } else { "#fff3cd" }
+            ));
+        }
+        attributes.insert(0, format!("label=\"{}\"", label));
+        let _ = writeln!(dot, "  n{} [{}];", node.id, attributes.join(", "));
+    }
+
+    for edge in &graph.edges {
+        let on_cycle = matches!(
+            (graph.nodes[edge.from].cycle, graph.nodes[edge.to].cycle),
+            (Some(a), Some(b)) if a == b
+        );
+        if on_cycle {
+            let _ = writeln!(dot, "  n{} -> n{} [color=\"#198754\", penwidth=2];", edge.from, edge.to);
+        } else {
+            let _ = writeln!(dot, "  n{} -> n{};", edge.from, edge.to);
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
@@ -0,0 +1,273 @@
+// ============================================================================
+// TERMINAL STEPPER
+// ============================================================================
+//
+// Line-based REPL that steps an algorithm run forward and backward in a plain
+// terminal (ANSI colors, no extra dependencies). The run is recorded once with
+// `record_trace`, so stepping backward replays exactly what validation saw.
+//
+// Commands: [n]ext (or Enter), [p]rev, [f]irst, [l]ast, [g]oto N, [q]uit
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use crate::{
+    modules::{
+        execution_trace::{ExecutionTrace, RobotStepTrace},
+        final_rule::FinalRule,
+        grid_experiment::GridExperiment,
+        position::Position,
+        view::display_view,
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::build_grid_experiments,
+        trace::{record_trace, robot_colors},
+    },
+};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const HIGHLIGHT: &str = "\x1b[1;33m";
+
+/// Loads `algo_path`, resolves `grid_spec` and starts the REPL. Returns false on bad input.
+///
+/// `grid_spec` is an experiment id of the validation configs, or a JSON file holding
+/// a `GridExperiment` (or a trace written by `--trace`).
+pub fn run_stepper(algo_path: &str, grid_spec: &str) -> bool {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return false;
+        }
+    };
+    let Some((experiment_id, grid_experiment)) = resolve_grid_spec(grid_spec) else {
+        return false;
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+    let trace = record_trace(
+        algo.name.clone(),
+        &final_rules,
+        visibility,
+        experiment_id,
+        &grid_experiment,
+    );
+
+    let mut stepper = Stepper {
+        trace: &trace,
+        final_rules: &final_rules,
+        colors: robot_colors(),
+        use_color: io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+        current: 0,
+    };
+    stepper.run();
+    true
+}
+
+fn resolve_grid_spec(grid_spec: &str) -> Option<(usize, GridExperiment)> {
+    if let Ok(experiment_id) = grid_spec.parse::<usize>() {
+        let list_of_grid_experiment = build_grid_experiments();
+        return match list_of_grid_experiment.get(experiment_id) {
+            Some(grid_experiment) => Some((experiment_id, grid_experiment.clone())),
+            None => {
+                eprintln!(
+                    "❌ Experiment {} does not exist ({} experiments)",
+                    experiment_id,
+                    list_of_grid_experiment.len()
+                );
+                None
+            }
+        };
+    }
+
+    let content = match fs::read_to_string(grid_spec) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("❌ Failed to read grid spec {}: {}", grid_spec, e);
+            return None;
+        }
+    };
+    if let Ok(grid_experiment) = serde_json::from_str::<GridExperiment>(&content) {
+        return Some((grid_experiment.id, grid_experiment));
+    }
+    match serde_json::from_str::<ExecutionTrace>(&content) {
+        Ok(trace) => Some((trace.experiment_id, trace.grid_experiment)),
+        Err(e) => {
+            eprintln!(
+                "❌ {} is neither a GridExperiment nor a trace: {}",
+                grid_spec, e
+            );
+            None
+        }
+    }
+}
+
+struct Stepper<'a> {
+    trace: &'a ExecutionTrace,
+    final_rules: &'a [FinalRule],
+    colors: HashMap<char, u32>,
+    use_color: bool,
+    /// Round shown; `rounds.len()` is the final state
+    current: usize,
+}
+
+impl Stepper<'_> {
+    fn run(&mut self) {
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+
+        loop {
+            self.render();
+            print!("[n]ext [p]rev [f]irst [l]ast [g]oto N [q]uit > ");
+            io::stdout().flush().unwrap();
+
+            let Some(Ok(line)) = lines.next() else {
+                println!();
+                return;
+            };
+            let mut words = line.split_whitespace();
+            let last = self.trace.rounds.len();
+            match words.next().unwrap_or("n") {
+                "n" | "next" => self.current = (self.current + 1).min(last),
+                "p" | "prev" => self.current = self.current.saturating_sub(1),
+                "f" | "first" => self.current = 0,
+                "l" | "last" => self.current = last,
+                "g" | "goto" => match words.next().and_then(|n| n.parse::<usize>().ok()) {
+                    // Rounds are numbered from 1 like in the trace
+                    Some(round) => self.current = round.saturating_sub(1).min(last),
+                    None => println!("goto expects a round number"),
+                },
+                "q" | "quit" => return,
+                other => println!("Unknown command: {}", other),
+            }
+        }
+    }
+
+    fn paint(&self, text: &str, style: &str) -> String {
+        if self.use_color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn paint_robot(&self, letter: char) -> String {
+        match self.colors.get(&letter) {
+            Some(rgb) if self.use_color => format!(
+                "\x1b[1;38;2;{};{};{}m{}{}",
+                (rgb >> 16) & 0xff,
+                (rgb >> 8) & 0xff,
+                rgb & 0xff,
+                letter,
+                RESET
+            ),
+            _ => letter.to_string(),
+        }
+    }
+
+    fn render(&self) {
+        if self.use_color {
+            print!("\x1b[2J\x1b[H");
+        }
+        let trace = self.trace;
+        let total = trace.rounds.len();
+
+        println!(
+            "{} · experiment {} · {} · {} rounds{}",
+            self.paint(&trace.algorithm, BOLD),
+            trace.experiment_id,
+            trace.status.to_string(),
+            total,
+            if trace.cycle_len > 0 {
+                format!(" · cycle length {}", trace.cycle_len)
+            } else {
+                String::new()
+            }
+        );
+
+        if self.current == total {
+            println!("{}", self.paint("Final state", HIGHLIGHT));
+            self.render_grid(&trace.final_state);
+            return;
+        }
+
+        let round = &trace.rounds[self.current];
+        println!(
+            "{}",
+            self.paint(&format!("Round {} / {}", round.round, total), HIGHLIGHT)
+        );
+        self.render_grid(&round.state);
+        for step in &round.robots {
+            self.render_robot_step(step);
+        }
+    }
+
+    /// Walls are the grid bounds, as in `calculate_view_with_walls`; y grows upward
+    fn render_grid(&self, state: &[Position]) {
+        let grid = &self.trace.grid_experiment.grid_config;
+        for y in (grid.min_y..=grid.max_y).rev() {
+            let mut line = String::new();
+            for x in grid.min_x..=grid.max_x {
+                let cell = if let Some(&(letter, _, _)) =
+                    state.iter().find(|&&(_, px, py)| px == x && py == y)
+                {
+                    self.paint_robot(letter)
+                } else if x == grid.min_x || x == grid.max_x || y == grid.min_y || y == grid.max_y
+                {
+                    self.paint("#", DIM)
+                } else {
+                    self.paint(".", DIM)
+                };
+                line.push_str(&cell);
+                line.push(' ');
+            }
+            println!("{}", line);
+        }
+        println!();
+    }
+
+    fn render_robot_step(&self, step: &RobotStepTrace) {
+        let (letter, x, y) = step.position;
+        let (new_letter, new_x, new_y) = step.new_position;
+        let movement = match &step.matched_rule {
+            Some(rule_match) => self.paint(
+                &format!(
+                    "rule #{} rotated {}° → {:?}, {} ({}, {})",
+                    rule_match.rule_index, rule_match.rotation, rule_match.direction, new_letter,
+                    new_x, new_y
+                ),
+                HIGHLIGHT,
+            ),
+            None => "no rule matched, stays".to_string(),
+        };
+        println!(
+            "Robot {} {} ({}, {}): {}",
+            step.robot,
+            self.paint_robot(letter),
+            x,
+            y,
+            movement
+        );
+
+        println!("  View:");
+        display_view(&step.view, &self.trace.visibility);
+        if let Some(rule_match) = &step.matched_rule {
+            let rule = &self.final_rules[rule_match.rule_index];
+            println!(
+                "  {} (direction {:?}, color {}):",
+                self.paint(&format!("Rule #{}", rule_match.rule_index), HIGHLIGHT),
+                rule.direction,
+                rule.color
+            );
+            display_view(&rule.view, &self.trace.visibility);
+        }
+        println!();
+    }
+}
@@ -0,0 +1,473 @@
+// ============================================================================
+// SVG RENDERING
+// ============================================================================
+//
+// Vector versions of the ASCII diamonds `generate_web_algo_string_rule` prints:
+// one card per `FinalRule` (view, arrow for the direction, new color), a rule
+// sheet for a whole algorithm, and an exploration trace either as a single
+// animated SVG (SMIL, plays in browsers) or as numbered frames for papers.
+// Colors come from `web_algo_colors`; 'W' cells are walls.
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    modules::{
+        direction::Direction,
+        execution_trace::{ExecutionTrace, RobotStepTrace},
+        final_rule::FinalRule,
+        grid_config::GridConfig,
+        position::Position,
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::build_grid_experiments,
+        trace::{record_trace, robot_colors},
+    },
+};
+
+pub const SVG_FOLDER: &str = "_svg";
+
+const CELL: i32 = 28;
+const CARD_PADDING: i32 = 12;
+const CARD_LABEL: i32 = 22;
+const SHEET_COLUMNS: usize = 6;
+const FRAME_SECONDS: f64 = 0.6;
+const UNKNOWN_COLOR: &str = "#6c757d";
+const WALL_COLOR: &str = "#adb5bd";
+const CELL_COLOR: &str = "#f8f9fa";
+const LINE_COLOR: &str = "#dee2e6";
+
+/// Writes the rule sheet of `algo_path` and, with an experiment id, its trace as an
+/// animated SVG or as numbered frames. Returns the folder written to.
+pub fn render_algorithm_svg(
+    algo_path: &str,
+    experiment_id: Option<usize>,
+    frames: bool,
+) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+    let svg_path = algo.output_folder(SVG_FOLDER);
+    if let Err(e) = fs::create_dir_all(&svg_path) {
+        eprintln!("❌ Failed to create {}: {}", svg_path.display(), e);
+        return None;
+    }
+    let palette = robot_colors();
+
+    let sheet_path = svg_path.join(format!("{}_rules.svg", algo.stem));
+    fs::write(
+        &sheet_path,
+        rule_sheet_svg(&algo.name, &final_rules, visibility, &palette),
+    )
+    .expect("Failed to write rule sheet");
+    println!(
+        "Rule sheet ({} rules) written to '{}'",
+        final_rules.len(),
+        sheet_path.display()
+    );
+
+    let Some(experiment_id) = experiment_id else {
+        return Some(svg_path);
+    };
+    let list_of_grid_experiment = build_grid_experiments();
+    let Some(grid_experiment) = list_of_grid_experiment.get(experiment_id) else {
+        eprintln!(
+            "❌ Experiment {} does not exist ({} experiments)",
+            experiment_id,
+            list_of_grid_experiment.len()
+        );
+        return None;
+    };
+    let trace = record_trace(
+        algo.name,
+        &final_rules,
+        visibility,
+        experiment_id,
+        grid_experiment,
+    );
+
+    if frames {
+        let frames_path = svg_path.join(format!("{}_experiment_{}_frames", algo.stem, experiment_id));
+        if let Err(e) = fs::create_dir_all(&frames_path) {
+            eprintln!("❌ Failed to create {}: {}", frames_path.display(), e);
+            return None;
+        }
+        let frames = trace_frames_svg(&trace, &palette);
+        let digits = frames.len().to_string().len();
+        for (index, frame) in frames.iter().enumerate() {
+            let frame_path = frames_path.join(format!("frame_{:0width$}.svg", index, width = digits));
+            fs::write(&frame_path, frame).expect("Failed to write frame");
+        }
+        println!(
+            "{} frames written to '{}'",
+            frames.len(),
+            frames_path.display()
+        );
+    } else {
+        let animation_path = svg_path.join(format!("{}_experiment_{}.svg", algo.stem, experiment_id));
+        fs::write(&animation_path, trace_animated_svg(&trace, &palette))
+            .expect("Failed to write animated trace");
+        println!(
+            "Animated trace ({} rounds, {}) written to '{}'",
+            trace.rounds.len(),
+            trace.status.to_string(),
+            animation_path.display()
+        );
+    }
+
+    Some(svg_path)
+}
+
+/// A single rule card: the view diamond with the direction arrow and the new color
+pub fn final_rule_svg(rule: &FinalRule, visibility: i16, palette: &HashMap<char, u32>) -> String {
+    let (width, height) = card_size(visibility);
+    let mut svg = svg_header(width, height);
+    write_rule_card(&mut svg, None, rule, visibility, palette);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// All rules of an algorithm as numbered cards, `SHEET_COLUMNS` per row
+pub fn rule_sheet_svg(
+    title: &str,
+    rules: &[FinalRule],
+    visibility: i16,
+    palette: &HashMap<char, u32>,
+) -> String {
+    let (card_width, card_height) = card_size(visibility);
+    let columns = rules.len().clamp(1, SHEET_COLUMNS);
+    let rows = rules.len().div_ceil(SHEET_COLUMNS).max(1);
+    let title_height = 32;
+    let width = columns as i32 * card_width;
+    let height = title_height + rows as i32 * card_height;
+
+    let mut svg = svg_header(width.max(240), height);
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="22" font-size="16" font-weight="bold">{} · {} rules · visibility {}</text>"#,
+        CARD_PADDING,
+        escape(title),
+        rules.len(),
+        visibility
+    );
+    for (index, rule) in rules.iter().enumerate() {
+        let x = (index % SHEET_COLUMNS) as i32 * card_width;
+        let y = title_height + (index / SHEET_COLUMNS) as i32 * card_height;
+        let _ = writeln!(svg, r#"<g transform="translate({},{})">"#, x, y);
+        write_rule_card(&mut svg, Some(index), rule, visibility, palette);
+        svg.push_str("</g>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// One SVG per round plus the final state, numbered in order
+pub fn trace_frames_svg(trace: &ExecutionTrace, palette: &HashMap<char, u32>) -> Vec<String> {
+    let grid = &trace.grid_experiment.grid_config;
+    let (width, height) = grid_size(grid);
+    let total = trace.rounds.len();
+
+    let mut frames: Vec<String> = trace
+        .rounds
+        .iter()
+        .map(|round| {
+            let mut svg = svg_header(width, height);
+            write_grid(&mut svg, grid);
+            write_moves(&mut svg, grid, &round.robots);
+            write_robots(&mut svg, grid, &round.state, palette);
+            write_caption(&mut svg, &format!("Round {} / {}", round.round, total), height);
+            svg.push_str("</svg>\n");
+            svg
+        })
+        .collect();
+
+    let mut svg = svg_header(width, height);
+    write_grid(&mut svg, grid);
+    write_robots(&mut svg, grid, &trace.final_state, palette);
+    write_caption(
+        &mut svg,
+        &format!("Final state · {}", trace.status.to_string()),
+        height,
+    );
+    svg.push_str("</svg>\n");
+    frames.push(svg);
+    frames
+}
+
+/// The trace as one looping SVG: each frame is shown for `FRAME_SECONDS`
+pub fn trace_animated_svg(trace: &ExecutionTrace, palette: &HashMap<char, u32>) -> String {
+    let grid = &trace.grid_experiment.grid_config;
+    let (width, height) = grid_size(grid);
+    let total = trace.rounds.len();
+    let frame_count = total + 1;
+    let duration = frame_count as f64 * FRAME_SECONDS;
+
+    let mut svg = svg_header(width, height);
+    write_grid(&mut svg, grid);
+
+    for index in 0..frame_count {
+        let _ = writeln!(svg, r#"<g opacity="{}">"#, if index == 0 { 1 } else { 0 });
+        let _ = writeln!(svg, "{}", frame_animation(index, frame_count, duration));
+        if let Some(round) = trace.rounds.get(index) {
+            write_moves(&mut svg, grid, &round.robots);
+            write_robots(&mut svg, grid, &round.state, palette);
+            write_caption(&mut svg, &format!("Round {} / {}", round.round, total), height);
+        } else {
+            write_robots(&mut svg, grid, &trace.final_state, palette);
+            write_caption(
+                &mut svg,
+                &format!("Final state · {}", trace.status.to_string()),
+                height,
+            );
+        }
+        svg.push_str("</g>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Discrete opacity animation showing frame `index` during its slot of the loop
+fn frame_animation(index: usize, frame_count: usize, duration: f64) -> String {
+    let start = index as f64 / frame_count as f64;
+    let end = (index + 1) as f64 / frame_count as f64;
+    let (values, key_times) = if frame_count == 1 {
+        ("1".to_string(), "0".to_string())
+    } else if index == 0 {
+        ("1;0".to_string(), format!("0;{:.4}", end))
+    } else if index + 1 == frame_count {
+        ("0;1".to_string(), format!("0;{:.4}", start))
+    } else {
+        ("0;1;0".to_string(), format!("0;{:.4};{:.4}", start, end))
+    };
+    format!(
+        r#"<animate attributeName="opacity" calcMode="discrete" values="{}" keyTimes="{}" dur="{:.1}s" repeatCount="indefinite"/>"#,
+        values, key_times, duration
+    )
+}
+
+fn svg_header(width: i32, height: i32) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\">\n<rect width=\"{w}\" height=\"{h}\" fill=\"#fff\"/>\n",
+        w = width,
+        h = height
+    )
+}
+
+fn card_size(visibility: i16) -> (i32, i32) {
+    let side = (visibility as i32 * 2 + 1) * CELL;
+    (side + 2 * CARD_PADDING, side + 2 * CARD_PADDING + CARD_LABEL)
+}
+
+fn grid_size(grid: &GridConfig) -> (i32, i32) {
+    let columns = (grid.max_x - grid.min_x + 1) as i32;
+    let rows = (grid.max_y - grid.min_y + 1) as i32;
+    (columns * CELL, rows * CELL + CARD_LABEL)
+}
+
+fn write_rule_card(
+    svg: &mut String,
+    index: Option<usize>,
+    rule: &FinalRule,
+    visibility: i16,
+    palette: &HashMap<char, u32>,
+) {
+    let v = visibility as i32;
+    let side = (2 * v + 1) * CELL;
+    let origin = CARD_PADDING;
+    // Cell top-left corner of relative position (x, y), y grows upward
+    let corner = |x: i32, y: i32| (origin + (x + v) * CELL, origin + (v - y) * CELL);
+
+    for y in -v..=v {
+        for x in -v..=v {
+            if x.abs() + y.abs() <= v {
+                let (cx, cy) = corner(x, y);
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}"/>"#,
+                    cx, cy, CELL, CELL, CELL_COLOR, LINE_COLOR
+                );
+            }
+        }
+    }
+
+    for &(letter, x, y) in &rule.view {
+        let (cx, cy) = corner(x as i32, y as i32);
+        if letter == 'W' {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}"/>"#,
+                cx, cy, CELL, CELL, WALL_COLOR, LINE_COLOR
+            );
+        } else {
+            write_robot(svg, cx + CELL / 2, cy + CELL / 2, letter, palette);
+        }
+    }
+
+    let (center_x, center_y) = corner(0, 0);
+    let (center_x, center_y) = (center_x + CELL / 2, center_y + CELL / 2);
+    write_direction(svg, center_x, center_y, rule.direction);
+
+    let label_y = origin + side + CARD_LABEL - 6;
+    let label = match index {
+        Some(index) => format!("#{} → {}", index, rule.direction.as_web_algo_str()),
+        None => format!("→ {}", rule.direction.as_web_algo_str()),
+    };
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="12">{}</text>"#,
+        origin,
+        label_y,
+        escape(&label)
+    );
+    let swatch_x = origin + side - CELL / 2;
+    write_robot(svg, swatch_x, label_y - 4, rule.color, palette);
+}
+
+/// Arrow from the center robot toward the move, a ring for `Idle`
+fn write_direction(svg: &mut String, x: i32, y: i32, direction: Direction) {
+    let (dx, dy) = match direction {
+        Direction::Up => (0, -1),
+        Direction::Down => (0, 1),
+        Direction::Left => (-1, 0),
+        Direction::Right => (1, 0),
+        Direction::Idle => {
+            let _ = writeln!(
+                svg,
+                r##"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="#212529" stroke-width="2" stroke-dasharray="3 2"/>"##,
+                x,
+                y,
+                CELL / 2 - 1
+            );
+            return;
+        }
+    };
+    write_arrow(
+        svg,
+        x + dx * CELL / 3,
+        y + dy * CELL / 3,
+        x + dx * CELL,
+        y + dy * CELL,
+    );
+}
+
+fn write_arrow(svg: &mut String, x1: i32, y1: i32, x2: i32, y2: i32) {
+    let length = (((x2 - x1).pow(2) + (y2 - y1).pow(2)) as f64).sqrt().max(1.0);
+    let (ux, uy) = ((x2 - x1) as f64 / length, (y2 - y1) as f64 / length);
+    let head = CELL as f64 / 4.0;
+    let (bx, by) = (x2 as f64 - ux * head, y2 as f64 - uy * head);
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{}" y1="{}" x2="{:.1}" y2="{:.1}" stroke="#212529" stroke-width="2"/>"##,
+        x1, y1, bx, by
+    );
+    let _ = writeln!(
+        svg,
+        r##"<polygon points="{},{} {:.1},{:.1} {:.1},{:.1}" fill="#212529"/>"##,
+        x2,
+        y2,
+        bx - uy * head / 2.0,
+        by + ux * head / 2.0,
+        bx + uy * head / 2.0,
+        by - ux * head / 2.0
+    );
+}
+
+fn write_robot(svg: &mut String, x: i32, y: i32, letter: char, palette: &HashMap<char, u32>) {
+    let fill = palette
+        .get(&letter)
+        .map(|rgb| format!("#{:06x}", rgb))
+        .unwrap_or_else(|| UNKNOWN_COLOR.to_string());
+    let _ = writeln!(
+        svg,
+        r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+        x,
+        y,
+        CELL * 3 / 8,
+        fill
+    );
+    let _ = writeln!(
+        svg,
+        r##"<text x="{}" y="{}" font-size="13" font-weight="bold" fill="#fff" text-anchor="middle" dominant-baseline="central">{}</text>"##,
+        x,
+        y,
+        escape(&letter.to_string())
+    );
+}
+
+fn write_grid(svg: &mut String, grid: &GridConfig) {
+    for y in grid.min_y..=grid.max_y {
+        for x in grid.min_x..=grid.max_x {
+            let is_wall =
+                x == grid.min_x || x == grid.max_x || y == grid.min_y || y == grid.max_y;
+            let (cx, cy) = grid_corner(grid, x, y);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}"/>"#,
+                cx,
+                cy,
+                CELL,
+                CELL,
+                if is_wall { WALL_COLOR } else { "#fff" },
+                LINE_COLOR
+            );
+        }
+    }
+}
+
+fn grid_corner(grid: &GridConfig, x: i16, y: i16) -> (i32, i32) {
+    (
+        (x - grid.min_x) as i32 * CELL,
+        (grid.max_y - y) as i32 * CELL,
+    )
+}
+
+fn grid_center(grid: &GridConfig, x: i16, y: i16) -> (i32, i32) {
+    let (cx, cy) = grid_corner(grid, x, y);
+    (cx + CELL / 2, cy + CELL / 2)
+}
+
+fn write_robots(svg: &mut String, grid: &GridConfig, state: &[Position], palette: &HashMap<char, u32>) {
+    for &(letter, x, y) in state {
+        let (cx, cy) = grid_center(grid, x, y);
+        write_robot(svg, cx, cy, letter, palette);
+    }
+}
+
+/// Arrows for the moves decided during the round
+fn write_moves(svg: &mut String, grid: &GridConfig, steps: &[RobotStepTrace]) {
+    for step in steps {
+        let (_, x, y) = step.position;
+        let (_, new_x, new_y) = step.new_position;
+        if (x, y) != (new_x, new_y) {
+            let (x1, y1) = grid_center(grid, x, y);
+            let (x2, y2) = grid_center(grid, new_x, new_y);
+            write_arrow(svg, x1, y1, x2, y2);
+        }
+    }
+}
+
+fn write_caption(svg: &mut String, caption: &str, height: i32) {
+    let _ = writeln!(
+        svg,
+        r#"<text x="4" y="{}" font-size="13">{}</text>"#,
+        height - 6,
+        escape(caption)
+    );
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,301 @@
+// ============================================================================
+// TIKZ EXPORT
+// ============================================================================
+//
+// Turns an algorithm's `Vec<FinalRule>` into LaTeX for papers, written to
+// `_tikz/<algo>/` next to the algorithm:
+//   colors.tex      `\definecolor{roastF}` per robot color + shared TikZ styles
+//   rule_NN.tex     one tikzpicture per rule (view diamond, arrow, new color)
+//   rules_table.tex summary grouped by robot color, as in
+//                   `calculate_algo_infos_by_robot_colors`
+//   rules_by_color.tex  the rule figures in the same grouping
+//   main.tex        standalone document inputting all of the above
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    modules::{direction::Direction, final_rule::FinalRule},
+    validation::{
+        algorithm_loader::{is_algorithm_file, load_final_rules, AlgorithmPath},
+        logic::calculate_algo_infos_by_robot_colors,
+        trace::robot_colors,
+    },
+};
+
+pub const TIKZ_FOLDER: &str = "_tikz";
+
+const UNKNOWN_COLOR: u32 = 0x6c757d;
+
+/// Exports `path`, an algorithm file or a folder of them. Returns the number exported.
+pub fn export_tikz(path: &str) -> Option<usize> {
+    let path = Path::new(path);
+    let algo_paths: Vec<PathBuf> = if path.is_dir() {
+        let mut algo_paths: Vec<PathBuf> = match fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|entry| is_algorithm_file(entry))
+                .collect(),
+            Err(e) => {
+                eprintln!("❌ Failed to read {}: {}", path.display(), e);
+                return None;
+            }
+        };
+        algo_paths.sort();
+        algo_paths
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    if algo_paths.is_empty() {
+        eprintln!("❌ No algorithm files in {}", path.display());
+        return None;
+    }
+
+    for algo_path in &algo_paths {
+        let output_path = export_algorithm_tikz(algo_path)?;
+        println!(
+            "TikZ for {} written to '{}'",
+            algo_path.display(),
+            output_path.display()
+        );
+    }
+    Some(algo_paths.len())
+}
+
+fn export_algorithm_tikz(algo_path: &Path) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(algo_path) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+    let algo = AlgorithmPath::new(algo_path);
+    let output_path = algo.output_folder(TIKZ_FOLDER).join(&algo.stem);
+    if let Err(e) = fs::create_dir_all(&output_path) {
+        eprintln!("❌ Failed to create {}: {}", output_path.display(), e);
+        return None;
+    }
+    let palette = robot_colors();
+    let digits = final_rules.len().max(1).to_string().len();
+
+    let write = |file: String, latex: String| {
+        fs::write(output_path.join(file), latex).expect("Failed to write TikZ export");
+    };
+    write("colors.tex".to_string(), color_definitions(&final_rules, &palette));
+    for (index, rule) in final_rules.iter().enumerate() {
+        write(
+            rule_file_name(index, digits),
+            rule_tikz(index, rule, visibility),
+        );
+    }
+    write("rules_table.tex".to_string(), rules_table(&final_rules));
+    write(
+        "rules_by_color.tex".to_string(),
+        rules_by_color(&final_rules, digits),
+    );
+    write(
+        "main.tex".to_string(),
+        main_document(&algo.name, final_rules.len(), visibility),
+    );
+
+    Some(output_path)
+}
+
+fn rule_file_name(index: usize, digits: usize) -> String {
+    format!("rule_{:0width$}.tex", index, width = digits)
+}
+
+/// `\definecolor{roastX}` for every palette letter and every letter the rules use,
+/// so rule files never reference an undefined color
+fn color_definitions(rules: &[FinalRule], palette: &HashMap<char, u32>) -> String {
+    let mut letters: Vec<char> = palette.keys().copied().collect();
+    for rule in rules {
+        letters.push(rule.color);
+        letters.extend(rule.view.iter().map(|&(letter, _, _)| letter));
+    }
+    letters.retain(|letter| letter.is_ascii_alphabetic() && *letter != 'W' && *letter != 'X');
+    letters.sort_unstable();
+    letters.dedup();
+
+    let mut latex = String::from("% Robot colors from web_algo_colors. Requires \\usetikzlibrary{arrows.meta}.\n");
+    for letter in letters {
+        let rgb = palette.get(&letter).copied().unwrap_or(UNKNOWN_COLOR);
+        let _ = writeln!(latex, "\\definecolor{{roast{}}}{{HTML}}{{{:06X}}}", letter, rgb);
+    }
+    latex.push_str(
+        r"\tikzset{
+  roast cell/.style={draw=black!20, fill=black!3},
+  roast wall/.style={draw=black!20, fill=black!35},
+  roast opaque/.style={draw=black!20, fill=black!75},
+  roast robot/.style={circle, fill=#1, text=white, font=\bfseries\scriptsize, inner sep=0pt, minimum size=3.8mm},
+  roast arrow/.style={-{Stealth[length=1.6mm]}, thick},
+  roast idle/.style={draw, thick, dashed, circle, minimum size=4.8mm},
+}
+% Inline colored robot, e.g. \RoastRobot{F}
+\newcommand{\RoastRobot}[1]{\tikz[baseline=-0.6ex]\node[roast robot=roast#1]{#1};}
+",
+    );
+    latex
+}
+
+/// One rule as a tikzpicture; one unit per cell, y grows upward as in the views
+fn rule_tikz(index: usize, rule: &FinalRule, visibility: i16) -> String {
+    let v = visibility;
+    let mut latex = String::new();
+    let _ = writeln!(latex, "% Rule #{}", index);
+    latex.push_str("\\begin{tikzpicture}[scale=0.5]\n");
+
+    for y in -v..=v {
+        for x in -v..=v {
+            if x.abs() + y.abs() <= v {
+                let style = match rule.view.iter().find(|&&(_, vx, vy)| vx == x && vy == y) {
+                    Some(('W', _, _)) => "roast wall",
+                    Some(('X', _, _)) => "roast opaque",
+                    _ => "roast cell",
+                };
+                let _ = writeln!(
+                    latex,
+                    "  \\draw[{}] ({},{}) rectangle ++(1,1);",
+                    style,
+                    x as f32 - 0.5,
+                    y as f32 - 0.5
+                );
+            }
+        }
+    }
+    for &(letter, x, y) in &rule.view {
+        if letter != 'W' && letter != 'X' {
+            let _ = writeln!(
+                latex,
+                "  \\node[roast robot=roast{}] at ({},{}) {{{}}};",
+                letter, x, y, letter
+            );
+        }
+    }
+
+    match direction_vector(rule.direction) {
+        Some((dx, dy)) => {
+            let _ = writeln!(
+                latex,
+                "  \\draw[roast arrow] ({},{}) -- ({},{});",
+                dx as f32 * 0.35,
+                dy as f32 * 0.35,
+                dx as f32 * 0.95,
+                dy as f32 * 0.95
+            );
+        }
+        None => latex.push_str("  \\node[roast idle] at (0,0) {};\n"),
+    }
+
+    let _ = writeln!(
+        latex,
+        "  \\node[anchor=north, font=\\scriptsize] at (0,{}) {{\\#{} $\\to$ {}, \\RoastRobot{{{}}}}};",
+        -(v as f32) - 0.6,
+        index,
+        rule.direction.as_web_algo_str(),
+        rule.color
+    );
+    latex.push_str("\\end{tikzpicture}\n");
+    latex
+}
+
+/// Summary table with one row per robot color, in `calculate_algo_infos_by_robot_colors` order
+fn rules_table(rules: &[FinalRule]) -> String {
+    let (infos_by_colors, total_activation) = calculate_algo_infos_by_robot_colors(rules);
+
+    let mut latex = String::from(
+        "\\begin{tabular}{c r r r r r r l}\n  \\hline\n  Color & Rules & Idle & Opacity & Color act. & Move act. & Total act. & Rule ids \\\\\n  \\hline\n",
+    );
+    for info in &infos_by_colors {
+        let ids: Vec<String> = rules_of_color(rules, info.robot_color)
+            .map(|index| index.to_string())
+            .collect();
+        let _ = writeln!(
+            latex,
+            "  \\RoastRobot{{{}}} & {} & {} & {} & {} & {} & {} & {} \\\\",
+            info.robot_color,
+            info.rules_count,
+            info.idle_rules_count,
+            info.opacity_rule_count,
+            info.color_activations,
+            info.movement_activations,
+            info.total_activation,
+            ids.join(", ")
+        );
+    }
+    let _ = writeln!(
+        latex,
+        "  \\hline\n  \\multicolumn{{6}}{{r}}{{Total activation}} & {} & \\\\\n  \\hline\n\\end{{tabular}}",
+        total_activation
+    );
+    latex
+}
+
+/// The rule figures grouped the same way as `rules_table.tex`
+fn rules_by_color(rules: &[FinalRule], digits: usize) -> String {
+    let (infos_by_colors, _) = calculate_algo_infos_by_robot_colors(rules);
+
+    let mut latex = String::new();
+    for info in &infos_by_colors {
+        let _ = writeln!(
+            latex,
+            "\\paragraph{{\\RoastRobot{{{}}} ({} rules)}}\n",
+            info.robot_color, info.rules_count
+        );
+        for index in rules_of_color(rules, info.robot_color) {
+            let _ = writeln!(
+                latex,
+                "\\input{{{}}}\\hspace{{1em}}%",
+                rule_file_name(index, digits)
+            );
+        }
+        latex.push('\n');
+    }
+    latex
+}
+
+fn rules_of_color(rules: &[FinalRule], color: char) -> impl Iterator<Item = usize> + '_ {
+    rules
+        .iter()
+        .enumerate()
+        .filter(move |(_, rule)| rule.color == color)
+        .map(|(index, _)| index)
+}
+
+fn main_document(algo_name: &str, rule_count: usize, visibility: i16) -> String {
+    format!(
+        r"\documentclass{{article}}
+\usepackage[margin=2cm]{{geometry}}
+\usepackage{{tikz}}
+\usetikzlibrary{{arrows.meta}}
+\input{{colors.tex}}
+
+\begin{{document}}
+\section*{{{title} ({rule_count} rules, visibility {visibility})}}
+
+\input{{rules_table.tex}}
+
+\input{{rules_by_color.tex}}
+\end{{document}}
+",
+        title = algo_name.replace('_', "\\_"),
+        rule_count = rule_count,
+        visibility = visibility
+    )
+}
+
+fn direction_vector(direction: Direction) -> Option<(i16, i16)> {
+    match direction {
+        Direction::Up => Some((0, 1)),
+        Direction::Down => Some((0, -1)),
+        Direction::Left => Some((-1, 0)),
+        Direction::Right => Some((1, 0)),
+        Direction::Idle => None,
+    }
+}
@@ -0,0 +1,323 @@
+// ============================================================================
+// EXECUTION TRACE
+// ============================================================================
+//
+// Replays one algorithm on one `GridExperiment` and records every round:
+// the state, each robot's computed view, the matched rule and rotation and
+// the resulting move. Written as JSON plus an HTML stepper next to the
+// algorithm in `_traces/`. Works for any outcome (validated, blocked, cyclic).
+//
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    methodology::configuration::CONFIG,
+    modules::{
+        execution_trace::ExecutionTrace, final_rule::FinalRule, grid_experiment::GridExperiment,
+        viewer_assets::viewer_styles,
+    },
+    validation::{
+        algorithm_loader::{load_final_rules, AlgorithmPath},
+        logic::{build_grid_experiments, simulate_exploration_traced},
+    },
+};
+
+pub const TRACES_FOLDER: &str = "_traces";
+
+/// Traces `algo_path` on experiment `experiment_id` and returns the written JSON path
+pub fn trace_experiment(algo_path: &str, experiment_id: usize) -> Option<PathBuf> {
+    let (final_rules, visibility) = match load_final_rules(Path::new(algo_path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return None;
+        }
+    };
+
+    let list_of_grid_experiment = build_grid_experiments();
+    let Some(grid_experiment) = list_of_grid_experiment.get(experiment_id) else {
+        eprintln!(
+            "❌ Experiment {} does not exist ({} experiments, ids 0..{})",
+            experiment_id,
+            list_of_grid_experiment.len(),
+            list_of_grid_experiment.len().saturating_sub(1)
+        );
+        return None;
+    };
+
+    let algo = AlgorithmPath::new(Path::new(algo_path));
+    let trace = record_trace(
+        algo.name.clone(),
+        &final_rules,
+        visibility,
+        experiment_id,
+        grid_experiment,
+    );
+
+    let traces_path = algo.output_folder(TRACES_FOLDER);
+    if let Err(e) = fs::create_dir_all(&traces_path) {
+        eprintln!("❌ Failed to create {}: {}", traces_path.display(), e);
+        return None;
+    }
+
+    println!(
+        "{} {} on experiment {}: {} rounds",
+        trace.status.to_string(),
+        trace.algorithm,
+        experiment_id,
+        trace.rounds.len()
+    );
+    Some(write_trace(
+        &trace,
+        &traces_path,
+        &format!("{}_experiment_{}", algo.stem, experiment_id),
+    ))
+}
+
+/// Writes `<file_stem>.json` and its HTML stepper to `folder` and returns the JSON path
+pub fn write_trace(trace: &ExecutionTrace, folder: &Path, file_stem: &str) -> PathBuf {
+    let json_path = folder.join(format!("{}.json", file_stem));
+    let html_path = folder.join(format!("{}.html", file_stem));
+
+    let trace_json = serde_json::to_string_pretty(trace).expect("Failed to serialize trace");
+    fs::write(&json_path, &trace_json).expect("Failed to write trace JSON");
+    fs::write(&html_path, generate_trace_html(trace, &trace_json))
+        .expect("Failed to write trace viewer");
+
+    println!("Trace written to '{}'", json_path.display());
+    println!("Stepper written to '{}'", html_path.display());
+    json_path
+}
+
+/// Runs the algorithm on `grid_experiment`, recording every round
+pub fn record_trace(
+    algorithm: String,
+    final_rules: &[FinalRule],
+    visibility: i16,
+    experiment_id: usize,
+    grid_experiment: &GridExperiment,
+) -> ExecutionTrace {
+    let mut rounds = Vec::new();
+    let (status, experiment_result) = simulate_exploration_traced(
+        experiment_id,
+        grid_experiment,
+        final_rules,
+        visibility,
+        Some(&mut rounds),
+    );
+
+    let final_state = rounds
+        .last()
+        .map(|round| round.robots.iter().map(|step| step.new_position).collect())
+        .unwrap_or_else(|| grid_experiment.init_config.initial_positions.clone());
+
+    ExecutionTrace {
+        algorithm,
+        experiment_id,
+        grid_experiment: grid_experiment.clone(),
+        visibility,
+        status,
+        cycle_len: experiment_result.cycle_len,
+        rounds,
+        final_state,
+    }
+}
+
+/// Robot colors from `web_algo_colors` ("F: 255" lines, decimal RGB)
+pub fn robot_colors() -> HashMap<char, u32> {
+    CONFIG
+        .web_algo_colors
+        .lines()
+        .filter_map(|line| {
+            let (letter, value) = line.split_once(':')?;
+            let letter = letter.trim().chars().next()?;
+            let value = value.trim().parse::<u32>().ok()?;
+            Some((letter, value))
+        })
+        .collect()
+}
+
+fn robot_colors_json() -> String {
+    let colors: HashMap<String, String> = robot_colors()
+        .into_iter()
+        .map(|(letter, value)| (letter.to_string(), format!("#{:06x}", value)))
+        .collect();
+    serde_json::to_string(&colors).unwrap()
+}
+
+fn generate_trace_html(trace: &ExecutionTrace, trace_json: &str) -> String {
+    let bundled_css = viewer_styles();
+    let colors_json = robot_colors_json();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Trace - {algorithm} - Experiment {experiment_id}</title>
+    {bundled_css}
+    <style>
+        body {{
+            background: #f5f5f5;
+        }}
+        .panel {{
+            background: #fff;
+            border: 1px solid #dee2e6;
+            border-radius: 8px;
+            padding: 16px;
+            margin-bottom: 16px;
+        }}
+        canvas {{
+            border: 1px solid #dee2e6;
+            background: #fff;
+        }}
+        .view-cell {{
+            font-family: monospace;
+            font-size: 0.8rem;
+            white-space: pre;
+        }}
+        .no-match {{
+            color: #dc3545;
+            font-weight: 600;
+        }}
+    </style>
+</head>
+<body>
+    <div class="container mt-4">
+        <h3>{algorithm} &middot; Experiment {experiment_id}</h3>
+        <p class="text-muted" id="summary"></p>
+        <div class="row">
+            <div class="col-lg-6">
+                <div class="panel text-center">
+                    <canvas id="grid"></canvas>
+                    <div class="d-flex justify-content-center align-items-center mt-4">
+                        <div class="btn-group me-3">
+                            <button class="btn btn-outline-secondary btn-sm" onclick="show(0)">&laquo; First</button>
+                            <button class="btn btn-outline-secondary btn-sm" onclick="show(current - 1)">&lsaquo; Prev</button>
+                            <button class="btn btn-outline-secondary btn-sm" onclick="show(current + 1)">Next &rsaquo;</button>
+                            <button class="btn btn-outline-secondary btn-sm" onclick="show(trace.rounds.length)">Last &raquo;</button>
+                        </div>
+                        <span class="fw-bold" id="roundLabel"></span>
+                    </div>
+                    <input type="range" class="w-100 mt-4" id="slider" min="0" value="0" oninput="show(parseInt(this.value))">
+                </div>
+            </div>
+            <div class="col-lg-6">
+                <div class="panel">
+                    <h5>Robots</h5>
+                    <div class="table-responsive">
+                        <table class="table table-hover">
+                            <thead><tr><th>#</th><th>Position</th><th>View</th><th>Rule</th><th>Move</th></tr></thead>
+                            <tbody id="robots"></tbody>
+                        </table>
+                    </div>
+                </div>
+            </div>
+        </div>
+    </div>
+
+    <script>
+        const trace = {trace_json};
+        const colors = {colors_json};
+        const cellSize = 32;
+        const grid = trace.grid_experiment.grid_config;
+        let current = 0;
+
+        document.getElementById('summary').textContent =
+            `Status: ${{trace.status}} · ${{trace.rounds.length}} rounds` +
+            (trace.cycle_len > 0 ? ` · cycle length ${{trace.cycle_len}}` : '') +
+            ` · grid ${{grid.columns}}x${{grid.rows}} · visibility ${{trace.visibility}}`;
+
+        const slider = document.getElementById('slider');
+        slider.max = trace.rounds.length;
+
+        function stateAt(index) {{
+            return index < trace.rounds.length ? trace.rounds[index].state : trace.final_state;
+        }}
+
+        function drawState(state) {{
+            const canvas = document.getElementById('grid');
+            const width = grid.max_x - grid.min_x + 1;
+            const height = grid.max_y - grid.min_y + 1;
+            canvas.width = width * cellSize;
+            canvas.height = height * cellSize;
+            const ctx = canvas.getContext('2d');
+            ctx.clearRect(0, 0, canvas.width, canvas.height);
+
+            for (let x = grid.min_x; x <= grid.max_x; x++) {{
+                for (let y = grid.min_y; y <= grid.max_y; y++) {{
+                    const cx = (x - grid.min_x) * cellSize;
+                    const cy = (grid.max_y - y) * cellSize;
+                    const isWall = x === grid.min_x || x === grid.max_x || y === grid.min_y || y === grid.max_y;
+                    ctx.fillStyle = isWall ? '#adb5bd' : '#fff';
+                    ctx.fillRect(cx, cy, cellSize, cellSize);
+                    ctx.strokeStyle = '#dee2e6';
+                    ctx.strokeRect(cx, cy, cellSize, cellSize);
+                }}
+            }}
+
+            for (const [letter, x, y] of state) {{
+                const cx = (x - grid.min_x) * cellSize + cellSize / 2;
+                const cy = (grid.max_y - y) * cellSize + cellSize / 2;
+                ctx.beginPath();
+                ctx.arc(cx, cy, cellSize * 0.38, 0, 2 * Math.PI);
+                ctx.fillStyle = colors[letter] || '#6c757d';
+                ctx.fill();
+                ctx.fillStyle = '#fff';
+                ctx.font = 'bold 14px sans-serif';
+                ctx.textAlign = 'center';
+                ctx.textBaseline = 'middle';
+                ctx.fillText(letter, cx, cy);
+            }}
+        }}
+
+        function formatPosition(position) {{
+            return `${{position[0]}} (${{position[1]}}, ${{position[2]}})`;
+        }}
+
+        function formatView(view) {{
+            return view.map(([letter, x, y]) => `${{letter}}(${{x}},${{y}})`).join(' ');
+        }}
+
+        function show(index) {{
+            current = Math.max(0, Math.min(index, trace.rounds.length));
+            slider.value = current;
+            drawState(stateAt(current));
+
+            const body = document.getElementById('robots');
+            body.innerHTML = '';
+            if (current === trace.rounds.length) {{
+                document.getElementById('roundLabel').textContent = `Final state (${{trace.status}})`;
+                return;
+            }}
+            document.getElementById('roundLabel').textContent =
+                `Round ${{trace.rounds[current].round}} / ${{trace.rounds.length}}`;
+
+            for (const step of trace.rounds[current].robots) {{
+                const row = document.createElement('tr');
+                const rule = step.matched_rule
+                    ? `#${{step.matched_rule.rule_index}} rot ${{step.matched_rule.rotation}}°`
+                    : '<span class="no-match">no rule</span>';
+                const move = step.matched_rule
+                    ? `${{step.matched_rule.direction}} → ${{formatPosition(step.new_position)}}`
+                    : 'stays';
+                row.innerHTML =
+                    `<td>${{step.robot}}</td><td>${{formatPosition(step.position)}}</td>` +
+                    `<td class="view-cell">${{formatView(step.view)}}</td><td>${{rule}}</td><td>${{move}}</td>`;
+                body.appendChild(row);
+            }}
+        }}
+
+        show(0);
+    </script>
+</body>
+</html>
+"#,
+        algorithm = trace.algorithm,
+        experiment_id = trace.experiment_id,
+    )
+}